@@ -80,7 +80,7 @@ mod tests {
     use miden_objects::{crypto::dsa::rpo_falcon512, ONE};
     use vm_processor::utils::{Deserializable, Serializable};
 
-    use super::{create_basic_wallet, Account, AccountStorageMode, AccountType, AuthScheme};
+    use super::{create_basic_wallet, Account, AccountStorageMode, AccountType, AuthScheme, Word};
 
     #[test]
     fn test_create_basic_wallet() {
@@ -113,4 +113,18 @@ mod tests {
         let deserialized_wallet = Account::read_from_bytes(&bytes).unwrap();
         assert_eq!(wallet, deserialized_wallet);
     }
+
+    #[test]
+    fn test_basic_wallet_public_key() {
+        let pub_key = rpo_falcon512::PublicKey::new([ONE; 4]);
+        let (wallet, _) = create_basic_wallet(
+            [1; 32],
+            AuthScheme::RpoFalcon512 { pub_key },
+            AccountType::RegularAccountUpdatableCode,
+            AccountStorageMode::Public,
+        )
+        .unwrap();
+
+        assert_eq!(wallet.rpo_falcon_public_key().unwrap(), Word::from(pub_key));
+    }
 }
@@ -18,6 +18,15 @@ pub mod utils;
 // STANDARDIZED SCRIPTS
 // ================================================================================================
 
+/// Returns the tag of P2ID and P2IDR notes targeting the specified account.
+///
+/// The tag is built from the target's account ID for local execution, which allows a wallet to
+/// subscribe to notes addressed to it before any such note is received.
+pub fn compute_p2id_tag(target: AccountId) -> NoteTag {
+    NoteTag::from_account_id(target, NoteExecutionMode::Local)
+        .expect("tags for local execution can be built for any account")
+}
+
 /// Generates a P2ID note - pay to id note.
 ///
 /// This script enables the transfer of assets from the `sender` account to the `target` account
@@ -39,7 +48,7 @@ pub fn create_p2id_note<R: FeltRng>(
     let note_script = scripts::p2id();
 
     let inputs = NoteInputs::new(vec![target.into()])?;
-    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+    let tag = compute_p2id_tag(target);
     let serial_num = rng.draw_word();
 
     let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
@@ -48,6 +57,13 @@ pub fn create_p2id_note<R: FeltRng>(
     Ok(Note::new(vault, metadata, recipient))
 }
 
+/// Returns true if the specified note is executing the standard `P2ID` script.
+///
+/// Only the script is checked; the note's inputs are not inspected.
+pub fn is_p2id(note: &Note) -> bool {
+    note.script_root() == scripts::p2id().hash()
+}
+
 /// Generates a P2IDR note - pay to id with recall after a certain block height.
 ///
 /// This script enables the transfer of assets from the sender `sender` account to the `target`
@@ -72,7 +88,7 @@ pub fn create_p2idr_note<R: FeltRng>(
     let note_script = scripts::p2idr();
 
     let inputs = NoteInputs::new(vec![target.into(), recall_height.into()])?;
-    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+    let tag = compute_p2id_tag(target);
     let serial_num = rng.draw_word();
 
     let vault = NoteAssets::new(assets)?;
@@ -81,6 +97,42 @@ pub fn create_p2idr_note<R: FeltRng>(
     Ok(Note::new(vault, metadata, recipient))
 }
 
+/// Returns true if the `reclaimer` account can reclaim the specified P2IDR note at
+/// `current_block`.
+///
+/// A P2IDR note can be reclaimed only by its sender, and only once the current block height has
+/// reached the reclaim block height stored in the note's inputs. This mirrors the checks done by
+/// the `P2IDR` script for a non-target account without executing the note.
+///
+/// # Errors
+/// Returns an error if the note is not executing the standard `P2IDR` script or if its inputs are
+/// malformed.
+pub fn can_reclaim(
+    note: &Note,
+    current_block: u32,
+    reclaimer: AccountId,
+) -> Result<bool, NoteError> {
+    if note.script_root() != scripts::p2idr().hash() {
+        return Err(NoteError::InvalidP2idrNote(format!(
+            "note {} is not executing the P2IDR script",
+            note.id()
+        )));
+    }
+
+    let inputs = note.inputs().values();
+    if inputs.len() != 2 {
+        return Err(NoteError::InvalidP2idrNote(format!(
+            "expected 2 P2IDR note inputs, but found {}",
+            inputs.len()
+        )));
+    }
+
+    let reclaim_height =
+        u32::try_from(inputs[1].as_int()).map_err(|_| NoteError::InvalidNoteInputU32(inputs[1]))?;
+
+    Ok(note.metadata().sender() == reclaimer && current_block >= reclaim_height)
+}
+
 /// Generates a SWAP note - swap of assets between two accounts - and returns the note as well as
 /// [NoteDetails] for the payback note.
 ///
@@ -105,7 +157,7 @@ pub fn create_swap_note<R: FeltRng>(
 
     let payback_recipient_word: Word = payback_recipient.digest().into();
     let requested_asset_word: Word = requested_asset.into();
-    let payback_tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local)?;
+    let payback_tag = compute_p2id_tag(sender);
 
     let inputs = NoteInputs::new(vec![
         payback_recipient_word[0],
@@ -136,3 +188,262 @@ pub fn create_swap_note<R: FeltRng>(
 
     Ok((note, payback_note))
 }
+
+/// Checks that the provided payback note is the one the specified SWAP note expects.
+///
+/// The payback note is consistent with the SWAP note if its recipient digest matches the payback
+/// recipient committed to in the SWAP note's inputs, and if it carries exactly the asset requested
+/// by the SWAP note. Since [Note] dereferences to [NoteDetails], either a full note or the details
+/// returned by [create_swap_note] can be passed as `payback`.
+///
+/// # Errors
+/// Returns an error if:
+/// - The `swap` note is not executing the standard SWAP script or has malformed inputs.
+/// - The payback recipient does not match the one expected by the SWAP note.
+/// - The payback assets differ from the asset requested by the SWAP note.
+pub fn verify_swap_consistency(swap: &Note, payback: &NoteDetails) -> Result<(), NoteError> {
+    if swap.script_root() != scripts::swap().hash() {
+        return Err(NoteError::InvalidSwapNote(format!(
+            "note {} is not executing the SWAP script",
+            swap.id()
+        )));
+    }
+
+    let inputs = swap.inputs().values();
+    if inputs.len() != 10 {
+        return Err(NoteError::InvalidSwapNote(format!(
+            "expected 10 SWAP note inputs, but found {}",
+            inputs.len()
+        )));
+    }
+
+    let payback_recipient_word: Word = payback.recipient().digest().into();
+    if payback_recipient_word[..] != inputs[0..4] {
+        return Err(NoteError::InvalidSwapNote(format!(
+            "payback recipient {} does not match the one expected by the SWAP note",
+            payback.recipient().digest()
+        )));
+    }
+
+    let requested_asset_word: Word = [inputs[4], inputs[5], inputs[6], inputs[7]];
+    let requested_asset =
+        Asset::try_from(requested_asset_word).map_err(NoteError::InvalidAssetData)?;
+    if payback.assets().iter().ne([requested_asset].iter()) {
+        return Err(NoteError::InvalidSwapNote(
+            "payback assets do not match the asset requested by the SWAP note".into(),
+        ));
+    }
+
+    Ok(())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+
+    use miden_objects::{
+        accounts::{
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+            },
+            AccountId,
+        },
+        assets::FungibleAsset,
+        crypto::rand::RpoRandomCoin,
+        notes::{
+            compute_recipient, NoteAssets, NoteDetails, NoteExecutionMode, NoteInputs, NoteTag,
+            NoteType,
+        },
+        Felt, NoteError, Word, ZERO,
+    };
+
+    use super::{
+        can_reclaim, compute_p2id_tag, create_p2id_note, create_p2idr_note, create_swap_note,
+        is_p2id, scripts, verify_swap_consistency,
+    };
+
+    /// Returns the sender and target accounts of the P2ID(R) notes created in the tests below,
+    /// together with a deterministic random coin.
+    fn p2id_accounts() -> (AccountId, AccountId, RpoRandomCoin) {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+
+        (sender, target, RpoRandomCoin::new(Word::default()))
+    }
+
+    #[test]
+    fn test_p2id_note_metadata_summary() {
+        let (sender, target, mut rng) = p2id_accounts();
+        let aux = Felt::new(27);
+
+        let note =
+            create_p2id_note(sender, target, vec![], NoteType::Public, aux, &mut rng).unwrap();
+        let summary = note.metadata_summary();
+
+        assert_eq!(summary.sender, sender);
+        assert_eq!(summary.note_type, NoteType::Public);
+        assert_eq!(
+            summary.tag,
+            NoteTag::from_account_id(target, NoteExecutionMode::Local).unwrap()
+        );
+        assert_eq!(summary.aux, aux);
+    }
+
+    #[test]
+    fn test_compute_recipient_matches_p2id_note() {
+        let (sender, target, mut rng) = p2id_accounts();
+
+        let note =
+            create_p2id_note(sender, target, vec![], NoteType::Public, Felt::new(0), &mut rng)
+                .unwrap();
+
+        // the recipient of a P2ID note commits to the P2ID script and to the target account ID as
+        // the only note input
+        let inputs = NoteInputs::new(vec![target.into()]).unwrap();
+        assert_eq!(
+            compute_recipient(note.serial_num(), scripts::p2id().hash(), &inputs),
+            note.recipient().digest()
+        );
+
+        let other_inputs = NoteInputs::new(vec![sender.into()]).unwrap();
+        assert_ne!(
+            compute_recipient(note.serial_num(), scripts::p2id().hash(), &other_inputs),
+            note.recipient().digest()
+        );
+    }
+
+    #[test]
+    fn test_compute_p2id_tag() {
+        let (sender, target, mut rng) = p2id_accounts();
+
+        let note =
+            create_p2id_note(sender, target, vec![], NoteType::Private, Felt::new(0), &mut rng)
+                .unwrap();
+        assert_eq!(note.metadata().tag(), compute_p2id_tag(target));
+
+        let note = create_p2idr_note(
+            sender,
+            target,
+            vec![],
+            NoteType::Private,
+            Felt::new(0),
+            10,
+            &mut rng,
+        )
+        .unwrap();
+        assert_eq!(note.metadata().tag(), compute_p2id_tag(target));
+    }
+
+    #[test]
+    fn test_note_scripts_are_cached() {
+        for script in [scripts::p2id, scripts::p2idr, scripts::swap] {
+            let (script_1, script_2) = (script(), script());
+            assert_eq!(script_1.hash(), script_2.hash());
+            // the scripts are built only once, so all copies share the same MAST forest
+            assert!(Arc::ptr_eq(&script_1.mast(), &script_2.mast()));
+        }
+
+        let (sender, target, mut rng) = p2id_accounts();
+
+        let note =
+            create_p2idr_note(sender, target, vec![], NoteType::Public, Felt::new(0), 10, &mut rng)
+                .unwrap();
+        assert_eq!(note.script().hash(), scripts::p2idr().hash());
+    }
+
+    #[test]
+    fn test_verify_swap_consistency() {
+        let (sender, _, mut rng) = p2id_accounts();
+        let offered_faucet = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let requested_faucet = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+        let offered_asset = FungibleAsset::new(offered_faucet, 100).unwrap().into();
+        let requested_asset = FungibleAsset::new(requested_faucet, 50).unwrap().into();
+
+        let (swap, payback) = create_swap_note(
+            sender,
+            offered_asset,
+            requested_asset,
+            NoteType::Public,
+            ZERO,
+            &mut rng,
+        )
+        .unwrap();
+        assert!(verify_swap_consistency(&swap, &payback).is_ok());
+
+        // a payback note requesting a different amount is rejected
+        let wrong_asset = FungibleAsset::new(requested_faucet, 49).unwrap().into();
+        let wrong_assets = NoteDetails::new(
+            NoteAssets::new(vec![wrong_asset]).unwrap(),
+            payback.recipient().clone(),
+        );
+        assert!(matches!(
+            verify_swap_consistency(&swap, &wrong_assets),
+            Err(NoteError::InvalidSwapNote(_))
+        ));
+
+        // a payback note built for another SWAP note is rejected
+        let (_, other_payback) = create_swap_note(
+            sender,
+            offered_asset,
+            requested_asset,
+            NoteType::Public,
+            ZERO,
+            &mut rng,
+        )
+        .unwrap();
+        assert!(matches!(
+            verify_swap_consistency(&swap, &other_payback),
+            Err(NoteError::InvalidSwapNote(_))
+        ));
+    }
+
+    #[test]
+    fn test_can_reclaim() {
+        let (sender, target, mut rng) = p2id_accounts();
+
+        // mirrors the p2idr script test: the current block height is 4
+        let current_block = 4;
+        let note_in_time =
+            create_p2idr_note(sender, target, vec![], NoteType::Public, ZERO, 5, &mut rng).unwrap();
+        let note_reclaimable =
+            create_p2idr_note(sender, target, vec![], NoteType::Public, ZERO, 3, &mut rng).unwrap();
+
+        // case "in time": the sender cannot reclaim the note yet
+        assert!(!can_reclaim(&note_in_time, current_block, sender).unwrap());
+
+        // case "reclaimable": the sender can reclaim the note, but no other account can
+        assert!(can_reclaim(&note_reclaimable, current_block, sender).unwrap());
+        assert!(!can_reclaim(&note_reclaimable, current_block, target).unwrap());
+
+        // the reclaim height itself is inclusive
+        assert!(can_reclaim(&note_in_time, 5, sender).unwrap());
+
+        // a note executing a different script is rejected
+        let p2id_note =
+            create_p2id_note(sender, target, vec![], NoteType::Public, ZERO, &mut rng).unwrap();
+        assert!(matches!(
+            can_reclaim(&p2id_note, current_block, sender),
+            Err(NoteError::InvalidP2idrNote(_))
+        ));
+    }
+
+    #[test]
+    fn test_is_p2id() {
+        let (sender, target, mut rng) = p2id_accounts();
+
+        let p2id_note =
+            create_p2id_note(sender, target, vec![], NoteType::Public, ZERO, &mut rng).unwrap();
+        assert_eq!(p2id_note.script_root(), scripts::p2id().hash());
+        assert!(is_p2id(&p2id_note));
+
+        let p2idr_note =
+            create_p2idr_note(sender, target, vec![], NoteType::Public, ZERO, 10, &mut rng)
+                .unwrap();
+        assert!(!is_p2id(&p2idr_note));
+    }
+}
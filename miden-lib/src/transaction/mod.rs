@@ -8,7 +8,7 @@ use miden_objects::{
     transaction::{
         OutputNote, OutputNotes, TransactionArgs, TransactionInputs, TransactionOutputs,
     },
-    utils::{group_slice_elements, serde::Deserializable},
+    utils::{group_slice_elements, serde::Deserializable, sync::LazyLock},
     vm::{AdviceInputs, AdviceMap, Program, ProgramInfo, StackInputs, StackOutputs},
     Digest, Felt, TransactionOutputError, Word, EMPTY_WORD,
 };
@@ -49,6 +49,17 @@ const KERNEL_MAIN_BYTES: &[u8] =
 
 pub struct TransactionKernel;
 
+/// Wrapper around the [Assembler] cached by [TransactionKernel::assembler()].
+///
+/// [Assembler] is not marked as [Sync] because its source manager is held as a trait object
+/// without thread-safety bounds.
+struct CachedAssembler(Assembler);
+
+// SAFETY: the cached assembler is built with a [DefaultSourceManager], which guards its state with
+// a lock, and is only ever accessed to be cloned.
+unsafe impl Send for CachedAssembler {}
+unsafe impl Sync for CachedAssembler {}
+
 impl TransactionKernel {
     // KERNEL SOURCE CODE
     // --------------------------------------------------------------------------------------------
@@ -58,9 +69,11 @@ impl TransactionKernel {
     /// # Panics
     /// Panics if the transaction kernel source is not well-formed.
     pub fn kernel() -> KernelLibrary {
-        // TODO: make this static
-        KernelLibrary::read_from_bytes(KERNEL_LIB_BYTES)
-            .expect("failed to deserialize transaction kernel library")
+        static KERNEL_LIB: LazyLock<KernelLibrary> = LazyLock::new(|| {
+            KernelLibrary::read_from_bytes(KERNEL_LIB_BYTES)
+                .expect("failed to deserialize transaction kernel library")
+        });
+        KERNEL_LIB.clone()
     }
 
     /// Returns an AST of the transaction kernel executable program.
@@ -114,13 +127,20 @@ impl TransactionKernel {
 
     /// Returns a new Miden assembler instantiated with the transaction kernel and loaded with the
     /// Miden stdlib as well as with miden-lib.
+    ///
+    /// The assembler is built once and cached; this function returns a clone of the cached
+    /// instance. Thus, all returned assemblers share the same source manager.
     pub fn assembler() -> Assembler {
-        let source_manager = Arc::new(DefaultSourceManager::default());
-        Assembler::with_kernel(source_manager, Self::kernel())
-            .with_library(StdLibrary::default())
-            .expect("failed to load std-lib")
-            .with_library(MidenLib::default())
-            .expect("failed to load miden-lib")
+        static ASSEMBLER: LazyLock<CachedAssembler> = LazyLock::new(|| {
+            let source_manager = Arc::new(DefaultSourceManager::default());
+            let assembler = Assembler::with_kernel(source_manager, TransactionKernel::kernel())
+                .with_library(StdLibrary::default())
+                .expect("failed to load std-lib")
+                .with_library(MidenLib::default())
+                .expect("failed to load miden-lib");
+            CachedAssembler(assembler)
+        });
+        ASSEMBLER.0.clone()
     }
 
     // STACK INPUTS / OUTPUTS
@@ -333,3 +353,23 @@ impl TransactionKernel {
         assembler.with_library(library).expect("failed to add mock account code")
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::TransactionKernel;
+
+    #[test]
+    fn test_assembler_is_reusable() {
+        let source = "begin push.1 push.2 add drop end";
+
+        let program_1 = TransactionKernel::assembler().assemble_program(source).unwrap();
+        let program_2 = TransactionKernel::assembler().assemble_program(source).unwrap();
+
+        assert_eq!(program_1.hash(), program_2.hash());
+        assert_eq!(program_1.kernel(), program_2.kernel());
+        assert_eq!(TransactionKernel::kernel().kernel(), program_1.kernel());
+    }
+}
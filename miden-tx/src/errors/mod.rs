@@ -24,6 +24,10 @@ pub enum TransactionExecutorError {
         actual: Option<Felt>,
     },
     InvalidTransactionOutput(TransactionOutputError),
+    NoteNotConsumable {
+        note_id: NoteId,
+        reason: ExecutionError,
+    },
     TransactionHostCreationFailed(TransactionHostError),
 }
 
@@ -9,7 +9,7 @@ use miden_objects::{
     vm::StackOutputs,
     MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES, ZERO,
 };
-use vm_processor::{ExecutionOptions, RecAdviceProvider};
+use vm_processor::{ExecutionError, ExecutionOptions, RecAdviceProvider};
 use winter_maybe_async::{maybe_async, maybe_await};
 
 use super::{TransactionExecutorError, TransactionHost};
@@ -131,6 +131,9 @@ impl TransactionExecutor {
     /// # Errors:
     /// Returns an error if:
     /// - If required data can not be fetched from the [DataStore].
+    /// - If an assertion fails during the execution of one of the input notes, e.g. because the
+    ///   executing account is not allowed to consume it.
+    /// - If the transaction program fails to execute.
     #[maybe_async]
     pub fn execute_transaction(
         &self,
@@ -159,14 +162,21 @@ impl TransactionExecutor {
         )
         .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
 
-        // execute the transaction kernel
+        // execute the transaction kernel; failed assertions which occur while a note is being
+        // executed mean that the note rejected its consumption and are attributed to that note,
+        // while any other error (e.g., missing advice data) is a generic execution failure
         let result = vm_processor::execute(
             &TransactionKernel::main(),
             stack_inputs,
             &mut host,
             self.exec_options,
         )
-        .map_err(TransactionExecutorError::ExecuteTransactionProgramFailed)?;
+        .map_err(|err| match (host.tx_progress().note_in_progress(), err) {
+            (Some(note_id), err @ ExecutionError::FailedAssertion { .. }) => {
+                TransactionExecutorError::NoteNotConsumable { note_id, reason: err }
+            },
+            (_, err) => TransactionExecutorError::ExecuteTransactionProgramFailed(err),
+        })?;
 
         // Attempt to retrieve used account codes based on the advice map
         let account_codes = self
@@ -40,6 +40,14 @@ impl TransactionProgress {
         &self.epilogue
     }
 
+    /// Returns the ID of the note whose execution has started but not yet ended, if any.
+    pub fn note_in_progress(&self) -> Option<NoteId> {
+        self.note_execution
+            .last()
+            .filter(|(_, interval)| interval.end.is_none())
+            .map(|(note_id, _)| *note_id)
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
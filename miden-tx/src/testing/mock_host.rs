@@ -1,12 +1,12 @@
 // MOCK HOST
 // ================================================================================================
 
-use alloc::{rc::Rc, string::ToString, sync::Arc};
+use alloc::{rc::Rc, string::ToString, sync::Arc, vec::Vec};
 
 use miden_lib::transaction::TransactionEvent;
 use miden_objects::{
     accounts::{AccountHeader, AccountVaultDelta},
-    Digest,
+    Digest, Felt,
 };
 use vm_processor::{
     AdviceExtractor, AdviceInjector, AdviceInputs, AdviceProvider, AdviceSource, ContextId,
@@ -45,6 +45,15 @@ impl MockHost {
         }
     }
 
+    /// Returns the values stored under the specified key in the advice map of this host, or
+    /// `None` if the key is not present in the map.
+    ///
+    /// The lookup is performed against the advice provider of this host, and thus also reflects
+    /// the entries inserted into the advice map during execution.
+    pub fn get_mapped_values(&self, key: &Digest) -> Option<&[Felt]> {
+        self.adv_provider.get_mapped_values(key)
+    }
+
     /// Consumes `self` and returns the advice provider and account vault delta.
     pub fn into_parts(self) -> (MemAdviceProvider, AccountVaultDelta) {
         (self.adv_provider, AccountVaultDelta::default())
@@ -111,3 +120,61 @@ impl Host for MockHost {
         Ok(HostResponse::None)
     }
 }
+
+// MOCK HOST BUILDER
+// ================================================================================================
+
+/// A builder for [MockHost] instances.
+///
+/// By default, the host is built with empty advice inputs and a fresh [TransactionMastStore].
+pub struct MockHostBuilder {
+    account: AccountHeader,
+    advice_inputs: AdviceInputs,
+    mast_store: Rc<TransactionMastStore>,
+}
+
+impl MockHostBuilder {
+    /// Returns a new [MockHostBuilder] for a host executing code against the specified account.
+    pub fn new(account: AccountHeader) -> Self {
+        Self {
+            account,
+            advice_inputs: AdviceInputs::default(),
+            mast_store: Rc::new(TransactionMastStore::new()),
+        }
+    }
+
+    /// Sets the header of the account against which the host executes code.
+    pub fn account(mut self, account: AccountHeader) -> Self {
+        self.account = account;
+        self
+    }
+
+    /// Sets the advice inputs of the host, replacing any previously added advice map entries.
+    ///
+    /// The advice inputs must contain the procedure data of the account code.
+    pub fn advice_inputs(mut self, advice_inputs: AdviceInputs) -> Self {
+        self.advice_inputs = advice_inputs;
+        self
+    }
+
+    /// Adds the specified entry to the advice map of the host, replacing any existing entry under
+    /// the same key.
+    pub fn advice_map_entry(mut self, key: Digest, values: Vec<Felt>) -> Self {
+        self.advice_inputs.extend_map([(key, values)]);
+        self
+    }
+
+    /// Sets the MAST store from which the host loads the MAST forests of executed procedures.
+    pub fn mast_store(mut self, mast_store: Rc<TransactionMastStore>) -> Self {
+        self.mast_store = mast_store;
+        self
+    }
+
+    /// Builds the [MockHost].
+    ///
+    /// # Panics
+    /// Panics if the advice inputs do not contain the procedure data of the account code.
+    pub fn build(self) -> MockHost {
+        MockHost::new(self.account, self.advice_inputs, self.mast_store)
+    }
+}
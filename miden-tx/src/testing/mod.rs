@@ -1,6 +1,6 @@
 pub mod executor;
 
-pub use mock_host::MockHost;
+pub use mock_host::{MockHost, MockHostBuilder};
 mod mock_host;
 
 pub mod mock_chain;
@@ -47,6 +47,7 @@ pub struct TransactionContextBuilder {
     note_args: BTreeMap<NoteId, Word>,
     rng: ChaCha20Rng,
     mock_chain: Option<MockChain>,
+    block_num: Option<u32>,
 }
 
 impl TransactionContextBuilder {
@@ -64,6 +65,7 @@ impl TransactionContextBuilder {
             advice_inputs: Default::default(),
             note_args: BTreeMap::new(),
             mock_chain: None,
+            block_num: None,
         }
     }
 
@@ -90,6 +92,7 @@ impl TransactionContextBuilder {
             tx_script: None,
             note_args: BTreeMap::new(),
             mock_chain: None,
+            block_num: None,
         }
     }
 
@@ -115,6 +118,7 @@ impl TransactionContextBuilder {
             tx_script: None,
             note_args: BTreeMap::new(),
             mock_chain: None,
+            block_num: None,
         }
     }
 
@@ -140,6 +144,7 @@ impl TransactionContextBuilder {
             tx_script: None,
             note_args: BTreeMap::new(),
             mock_chain: None,
+            block_num: None,
         }
     }
 
@@ -168,6 +173,19 @@ impl TransactionContextBuilder {
         self
     }
 
+    /// Sets the number of the reference block used when executing the transaction.
+    ///
+    /// Blocks are sealed on the mock chain until the requested block number is reached. If not
+    /// set, four blocks are sealed after the chain's latest block.
+    ///
+    /// # Panics
+    /// Building the context panics if `block_num` is smaller than the number of the next block of
+    /// the mock chain, i.e., if the requested block has already been sealed.
+    pub fn block_num(mut self, block_num: u32) -> Self {
+        self.block_num = Some(block_num);
+        self
+    }
+
     pub fn expected_notes(mut self, output_notes: Vec<OutputNote>) -> Self {
         let output_notes = output_notes.into_iter().filter_map(|n| match n {
             OutputNote::Full(note) => Some(note),
@@ -566,8 +584,24 @@ impl TransactionContextBuilder {
             MockChainBuilder::default().notes(self.input_notes.clone()).build()
         };
 
-        for _ in 0..4 {
-            mock_chain.seal_block(None);
+        match self.block_num {
+            Some(block_num) => {
+                let mut block = mock_chain.seal_block(None);
+                while block.header().block_num() < block_num {
+                    block = mock_chain.seal_block(None);
+                }
+                assert_eq!(
+                    block.header().block_num(),
+                    block_num,
+                    "reference block number must be greater than or equal to the number of the next \
+                    block of the mock chain"
+                );
+            },
+            None => {
+                for _ in 0..4 {
+                    mock_chain.seal_block(None);
+                }
+            },
         }
 
         let mut tx_args = TransactionArgs::new(
@@ -3,6 +3,14 @@ use miden_lib::transaction::memory;
 // TEST HELPERS
 // ================================================================================================
 
+/// Returns the memory address at which the data of the input note with the specified index
+/// begins, i.e., `INPUT_NOTE_DATA_SECTION_OFFSET + note_idx * NOTE_MEM_SIZE`.
 pub fn input_note_data_ptr(note_idx: u32) -> memory::MemoryAddress {
     memory::INPUT_NOTE_DATA_SECTION_OFFSET + note_idx * memory::NOTE_MEM_SIZE
 }
+
+/// Returns an iterator over the data pointers of the first `num_notes` input notes, i.e.,
+/// `input_note_data_ptr(0), input_note_data_ptr(1), ..., input_note_data_ptr(num_notes - 1)`.
+pub fn input_note_data_ptrs(num_notes: u32) -> impl Iterator<Item = memory::MemoryAddress> {
+    (0..num_notes).map(input_note_data_ptr)
+}
@@ -1,8 +1,8 @@
-use alloc::{collections::BTreeMap, string::String};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 
 use miden_lib::{
     errors::tx_kernel_errors::ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_SENDER_FROM_INCORRECT_CONTEXT,
-    transaction::memory::CURRENT_INPUT_NOTE_PTR,
+    transaction::memory::{CURRENT_INPUT_NOTE_PTR, INPUT_NOTE_DATA_SECTION_OFFSET, NOTE_MEM_SIZE},
 };
 use miden_objects::{
     notes::Note, testing::prepare_word, transaction::TransactionArgs, Hasher, WORD_SIZE,
@@ -13,11 +13,35 @@ use super::{Felt, Process, ZERO};
 use crate::{
     assert_execution_error,
     testing::{
-        utils::input_note_data_ptr, MockHost, TransactionContext, TransactionContextBuilder,
+        utils::{input_note_data_ptr, input_note_data_ptrs},
+        MockHost, TransactionContext, TransactionContextBuilder,
     },
     tests::kernel_tests::read_root_mem_value,
 };
 
+#[test]
+fn test_input_note_data_ptr() {
+    // the data section of each input note follows the data section of the previous note
+    assert_eq!(input_note_data_ptr(0), INPUT_NOTE_DATA_SECTION_OFFSET);
+    assert_eq!(input_note_data_ptr(1), INPUT_NOTE_DATA_SECTION_OFFSET + NOTE_MEM_SIZE);
+    assert_eq!(input_note_data_ptr(2), INPUT_NOTE_DATA_SECTION_OFFSET + 2 * NOTE_MEM_SIZE);
+}
+
+#[test]
+fn test_input_note_data_ptrs() {
+    let ptrs: Vec<_> = input_note_data_ptrs(3).collect();
+    assert_eq!(
+        ptrs,
+        vec![
+            INPUT_NOTE_DATA_SECTION_OFFSET,
+            INPUT_NOTE_DATA_SECTION_OFFSET + NOTE_MEM_SIZE,
+            INPUT_NOTE_DATA_SECTION_OFFSET + 2 * NOTE_MEM_SIZE,
+        ]
+    );
+
+    assert_eq!(input_note_data_ptrs(0).count(), 0);
+}
+
 #[test]
 fn test_get_sender_no_sender() {
     let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
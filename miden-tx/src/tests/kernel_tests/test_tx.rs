@@ -41,14 +41,13 @@ use miden_objects::{
 };
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
-use vm_processor::AdviceInputs;
+use vm_processor::ExecutionError;
 
 use super::{Felt, Process, ProcessState, Word, ONE, ZERO};
 use crate::{
     assert_execution_error,
     testing::{
-        mock_chain::{MockChain, MockChainBuilder},
-        MockHost, TransactionContextBuilder,
+        mock_chain::MockChainBuilder, MockHost, TransactionContext, TransactionContextBuilder,
     },
     tests::kernel_tests::{read_root_mem_value, try_read_root_mem_value},
 };
@@ -676,24 +675,7 @@ fn test_load_foreign_account_basic() {
         .unwrap();
 
     let account_id = foreign_account.id();
-    let mock_chain = MockChainBuilder::default().accounts(vec![foreign_account.clone()]).build();
-    let advice_inputs = get_mock_advice_inputs(&foreign_account, &mock_chain);
-
-    // TODO: Temporary fix: Build a native account that has the same code commitment as the foreign
-    // account which is required for this test to pass right now.
-    let native_account_id =
-        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
-    let native_account = Account::from_parts(
-        native_account_id,
-        AssetVault::mock(),
-        foreign_account.storage().clone(),
-        foreign_account.code().clone(),
-        ONE,
-    );
-    let tx_context = TransactionContextBuilder::new(native_account)
-        .mock_chain(mock_chain)
-        .advice_inputs(advice_inputs.clone())
-        .build();
+    let tx_context = foreign_account_tx_context(&foreign_account);
 
     let code = format!(
         "
@@ -756,24 +738,7 @@ fn test_load_foreign_account_basic() {
         .unwrap();
 
     let account_id = foreign_account.id();
-    let mock_chain = MockChainBuilder::default().accounts(vec![foreign_account.clone()]).build();
-    let advice_inputs = get_mock_advice_inputs(&foreign_account, &mock_chain);
-
-    // TODO: Temporary fix: Build a native account that has the same code commitment as the foreign
-    // account which is required for this test to pass right now.
-    let native_account_id =
-        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
-    let native_account = Account::from_parts(
-        native_account_id,
-        AssetVault::mock(),
-        foreign_account.storage().clone(),
-        foreign_account.code().clone(),
-        ONE,
-    );
-    let tx_context = TransactionContextBuilder::new(native_account)
-        .mock_chain(mock_chain)
-        .advice_inputs(advice_inputs)
-        .build();
+    let tx_context = foreign_account_tx_context(&foreign_account);
 
     let code = format!(
         "
@@ -843,24 +808,7 @@ fn test_load_foreign_account_twice() {
         .unwrap();
 
     let account_id = foreign_account.id();
-    let mock_chain = MockChainBuilder::default().accounts(vec![foreign_account.clone()]).build();
-    let advice_inputs = get_mock_advice_inputs(&foreign_account, &mock_chain);
-
-    // TODO: Temporary fix: Build a native account that has the same code commitment as the foreign
-    // account which is required for this test to pass right now.
-    let native_account_id =
-        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
-    let native_account = Account::from_parts(
-        native_account_id,
-        AssetVault::mock(),
-        foreign_account.storage().clone(),
-        foreign_account.code().clone(),
-        ONE,
-    );
-    let tx_context = TransactionContextBuilder::new(native_account)
-        .mock_chain(mock_chain)
-        .advice_inputs(advice_inputs.clone())
-        .build();
+    let tx_context = foreign_account_tx_context(&foreign_account);
 
     let code = format!(
         "
@@ -923,35 +871,70 @@ fn test_load_foreign_account_twice() {
     );
 }
 
+/// This test checks that loading a foreign account fails if its data was not provided through
+/// `TransactionArgs::add_foreign_account()`.
+#[test]
+fn test_load_foreign_account_without_data() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let foreign_account_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+    let code = format!(
+        "
+        use.kernel::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            # pad the stack and push the index of the storage item, the hash of the `get_item`
+            # account procedure and the foreign account id
+            padw padw padw push.0.0 push.0
+            push.{get_item_foreign_hash}
+            push.{foreign_account_id}
+            # => [foreign_account_id, FOREIGN_PROC_ROOT, storage_item_index, pad(14)]
+
+            exec.tx::execute_foreign_procedure
+        end
+        ",
+        get_item_foreign_hash = get_root_of_get_item_procedure(),
+    );
+
+    // the kernel fails to find the core data of the foreign account in the advice map
+    let foreign_account_key: Word = [foreign_account_id.into(), ZERO, ZERO, ZERO];
+    assert!(matches!(
+        tx_context.execute_code(&code),
+        Err(ExecutionError::AdviceMapKeyNotFound(key)) if key == foreign_account_key
+    ));
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
-fn get_mock_advice_inputs(foreign_account: &Account, mock_chain: &MockChain) -> AdviceInputs {
-    let foreign_id_root = Digest::from([foreign_account.id().into(), ZERO, ZERO, ZERO]);
-    let foreign_id_and_nonce = [foreign_account.id().into(), ZERO, ZERO, foreign_account.nonce()];
-    let foreign_vault_root = foreign_account.vault().commitment();
-    let foreign_storage_root = foreign_account.storage().commitment();
-    let foreign_code_root = foreign_account.code().commitment();
-
-    AdviceInputs::default()
-        .with_map([
-            // ACCOUNT_ID |-> [ID_AND_NONCE, VAULT_ROOT, STORAGE_ROOT, CODE_ROOT]
-            (
-                foreign_id_root,
-                [
-                    &foreign_id_and_nonce,
-                    foreign_vault_root.as_elements(),
-                    foreign_storage_root.as_elements(),
-                    foreign_code_root.as_elements(),
-                ]
-                .concat(),
-            ),
-            // STORAGE_ROOT |-> [[STORAGE_SLOT_DATA]]
-            (foreign_storage_root, foreign_account.storage().as_elements()),
-            // CODE_ROOT |-> [[ACCOUNT_PROCEDURE_DATA]]
-            (foreign_code_root, foreign_account.code().as_elements()),
-        ])
-        .with_merkle_store(mock_chain.accounts().into())
+/// Returns a transaction context in which the data of the provided foreign account is supplied
+/// through `TransactionArgs::add_foreign_account()`.
+fn foreign_account_tx_context(foreign_account: &Account) -> TransactionContext {
+    let mock_chain = MockChainBuilder::default().accounts(vec![foreign_account.clone()]).build();
+    let account_path = mock_chain.accounts().open(&foreign_account.id().into()).path;
+
+    // TODO: Temporary fix: Build a native account that has the same code commitment as the foreign
+    // account which is required for the foreign account tests to pass right now.
+    let native_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let native_account = Account::from_parts(
+        native_account_id,
+        AssetVault::mock(),
+        foreign_account.storage().clone(),
+        foreign_account.code().clone(),
+        ONE,
+    );
+
+    let mut tx_context =
+        TransactionContextBuilder::new(native_account).mock_chain(mock_chain).build();
+    let mut tx_args = tx_context.tx_args().clone();
+    tx_args.add_foreign_account(foreign_account, &account_path).unwrap();
+    tx_context.set_tx_args(tx_args);
+
+    tx_context
 }
 
 fn foreign_account_data_memory_assertions(foreign_account: &Account, process: &Process<MockHost>) {
@@ -28,7 +28,7 @@ use miden_objects::{
     testing::{
         account_component::AccountMockComponent,
         constants::{FUNGIBLE_ASSET_AMOUNT, NON_FUNGIBLE_ASSET_DATA},
-        notes::DEFAULT_NOTE_CODE,
+        notes::{NoteBuilder, DEFAULT_NOTE_CODE},
         prepare_word,
         storage::{STORAGE_INDEX_0, STORAGE_INDEX_2},
     },
@@ -40,14 +40,17 @@ use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use vm_processor::{
     utils::{Deserializable, Serializable},
-    Digest, MemAdviceProvider, ONE,
+    Digest, ExecutionError, MemAdviceProvider, ONE,
 };
 
 use super::{
-    LocalTransactionProver, TransactionExecutor, TransactionHost, TransactionProver,
-    TransactionVerifier,
+    LocalTransactionProver, TransactionExecutor, TransactionExecutorError, TransactionHost,
+    TransactionProver, TransactionVerifier,
+};
+use crate::{
+    testing::{MockHostBuilder, TransactionContextBuilder},
+    TransactionMastStore,
 };
-use crate::{testing::TransactionContextBuilder, TransactionMastStore};
 
 mod kernel_tests;
 
@@ -120,6 +123,55 @@ fn transaction_executor_witness() {
     assert_eq!(executed_transaction.output_notes(), &tx_outputs.output_notes);
 }
 
+#[test]
+fn transaction_inputs_nullifiers() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let expected_nullifiers = tx_context
+        .input_notes()
+        .iter()
+        .map(|note| note.note().nullifier())
+        .collect::<Vec<_>>();
+
+    assert!(!expected_nullifiers.is_empty());
+    assert_eq!(tx_context.tx_inputs().input_nullifiers(), expected_nullifiers);
+}
+
+#[test]
+fn executed_transaction_output_note_ids_are_deterministic() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .with_mock_notes_preserved()
+        .build();
+
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let note_ids = tx_context
+        .tx_inputs()
+        .input_notes()
+        .iter()
+        .map(|note| note.id())
+        .collect::<Vec<_>>();
+
+    let executed_transaction_1 = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+    let executed_transaction_2 = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_context.tx_args().clone())
+        .unwrap();
+
+    let output_note_ids = executed_transaction_1.output_note_ids();
+    assert!(!output_note_ids.is_empty());
+    assert_eq!(output_note_ids, executed_transaction_2.output_note_ids());
+    assert_eq!(
+        executed_transaction_1.output_notes().commitment(),
+        executed_transaction_2.output_notes().commitment()
+    );
+}
+
 #[test]
 fn executed_transaction_account_delta_new() {
     let account_assets = AssetVault::mock().assets().collect::<Vec<Asset>>();
@@ -1003,3 +1055,55 @@ fn transaction_executor_account_code_using_custom_library() {
     // Account nonce should have been incremented by 4.
     assert_eq!(executed_tx.account_delta().nonce().unwrap(), Felt::new(4));
 }
+
+#[test]
+fn executor_reports_missing_note_advice_as_execution_failure() {
+    // the note script requests advice data which was never provided
+    let note = NoteBuilder::new(
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap(),
+        ChaCha20Rng::from_seed([0; 32]),
+    )
+    .code("begin push.1.2.3.4 adv.push_mapval dropw end")
+    .build(&TransactionKernel::testing_assembler())
+    .unwrap();
+
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE)
+        .input_notes(vec![note.clone()])
+        .build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), None);
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let result = executor.execute_transaction(
+        account_id,
+        block_ref,
+        &[note.id()],
+        tx_context.tx_args().clone(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(TransactionExecutorError::ExecuteTransactionProgramFailed(
+            ExecutionError::AdviceMapKeyNotFound(_)
+        ))
+    ));
+}
+
+#[test]
+fn mock_host_builder_exposes_advice_map() {
+    let tx_context = TransactionContextBuilder::with_standard_account(ONE).build();
+    let (_, advice_inputs) =
+        TransactionKernel::prepare_inputs(tx_context.tx_inputs(), tx_context.tx_args(), None);
+
+    let key = Digest::from([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let values = vec![Felt::new(5), Felt::new(6)];
+
+    let host = MockHostBuilder::new(tx_context.account().into())
+        .advice_inputs(advice_inputs)
+        .advice_map_entry(key, values.clone())
+        .build();
+
+    assert_eq!(host.get_mapped_values(&key), Some(values.as_slice()));
+    // the account procedure data inserted by the kernel is also available
+    assert!(host.get_mapped_values(&tx_context.account().code().commitment()).is_some());
+}
@@ -148,6 +148,48 @@ fn prove_p2id_script() {
     assert!(executed_transaction_2.is_err());
 }
 
+/// We test that the account update and the nullifiers of an executed P2ID consume transaction can
+/// be used to build a block.
+#[test]
+fn p2id_script_account_update_and_nullifiers() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, falcon_auth) = get_new_pk_and_authenticator();
+    let target_account =
+        get_account_with_basic_authenticated_wallet(target_account_id, target_pub_key, None);
+
+    let note = create_p2id_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        Felt::new(0),
+        &mut RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    let tx_context = TransactionContextBuilder::new(target_account.clone())
+        .input_notes(vec![note.clone()])
+        .build();
+    let executor = TransactionExecutor::new(Arc::new(tx_context.clone()), Some(falcon_auth));
+
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let tx_args = TransactionArgs::with_tx_script(build_default_auth_script());
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &[note.id()], tx_args)
+        .unwrap();
+
+    let (account_id, account_hash) = executed_transaction.to_account_update();
+    assert_eq!(account_id, target_account_id);
+    assert_eq!(account_hash, executed_transaction.final_account().hash());
+
+    assert_eq!(executed_transaction.consumed_nullifiers(), vec![note.nullifier()]);
+}
+
 /// We test the Pay to script with 2 assets to test the loop inside the script.
 /// So we create a note containing two assets that can only be consumed by the target account.
 #[test]
@@ -16,7 +16,7 @@ use miden_objects::{
     transaction::TransactionArgs,
     Felt,
 };
-use miden_tx::{testing::TransactionContextBuilder, TransactionExecutor};
+use miden_tx::{testing::TransactionContextBuilder, TransactionExecutor, TransactionExecutorError};
 
 use crate::{
     build_default_auth_script, get_account_with_basic_authenticated_wallet,
@@ -175,7 +175,10 @@ fn p2idr_script() {
 
     // Check that we got the expected result - TransactionExecutorError and not ExecutedTransaction
     // Third transaction should not work (malicious account can never consume), we expect an error
-    assert!(executed_transaction_3.is_err());
+    assert!(matches!(
+        executed_transaction_3,
+        Err(TransactionExecutorError::NoteNotConsumable { note_id, .. }) if note_id == note_in_time.id()
+    ));
 
     // CONSTRUCT AND EXECUTE TX (Case "reclaimable" - Execution Target Account Success)
     // --------------------------------------------------------------------------------------------
@@ -262,3 +265,53 @@ fn p2idr_script() {
     // Sixth transaction should not work (malicious account can never consume), we expect an error
     assert!(executed_transaction_6.is_err())
 }
+
+#[test]
+fn p2idr_script_reclaim_height_boundary() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (sender_pub_key, sender_falcon_auth) = get_new_pk_and_authenticator();
+    let sender_account =
+        get_account_with_basic_authenticated_wallet(sender_account_id, sender_pub_key, None);
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+
+    let reclaim_block_height = 10_u32;
+    let note = create_p2idr_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        Felt::new(0),
+        reclaim_block_height,
+        &mut RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // the sender can reclaim the note only once the reference block reaches the reclaim height
+    for (block_num, reclaimable) in [
+        (reclaim_block_height - 1, false),
+        (reclaim_block_height, true),
+        (reclaim_block_height + 1, true),
+    ] {
+        let tx_context = TransactionContextBuilder::new(sender_account.clone())
+            .input_notes(vec![note.clone()])
+            .block_num(block_num)
+            .build();
+        let executor = TransactionExecutor::new(
+            Arc::new(tx_context.clone()),
+            Some(sender_falcon_auth.clone()),
+        );
+
+        let block_ref = tx_context.tx_inputs().block_header().block_num();
+        assert_eq!(block_ref, block_num);
+        let note_ids = tx_context.input_notes().iter().map(|note| note.id()).collect::<Vec<_>>();
+        let tx_args = TransactionArgs::with_tx_script(build_default_auth_script());
+
+        let result = executor.execute_transaction(sender_account_id, block_ref, &note_ids, tx_args);
+        assert_eq!(result.is_ok(), reclaimable, "unexpected result at block {block_num}");
+    }
+}
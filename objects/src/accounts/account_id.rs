@@ -8,11 +8,14 @@ use super::{
     get_account_seed, AccountError, ByteReader, Deserializable, DeserializationError, Digest, Felt,
     Hasher, Serializable, Word, ZERO,
 };
-use crate::{crypto::merkle::LeafIndex, utils::hex_to_bytes, ACCOUNT_TREE_DEPTH};
+use crate::{crypto::merkle::LeafIndex, utils::hex_to_bytes, StarkField, ACCOUNT_TREE_DEPTH};
 
 // CONSTANTS
 // ================================================================================================
 
+/// The symbols of Crockford's base32 alphabet, which excludes the letters I, L, O and U.
+const CROCKFORD_BASE32_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
 // The higher two bits of the most significant nibble determines the account storage mode
 pub const ACCOUNT_STORAGE_MASK_SHIFT: u64 = 62;
 pub const ACCOUNT_STORAGE_MASK: u64 = 0b11 << ACCOUNT_STORAGE_MASK_SHIFT;
@@ -40,6 +43,16 @@ pub enum AccountType {
 }
 
 impl AccountType {
+    /// Returns all account types.
+    pub const fn all() -> [AccountType; 4] {
+        [
+            AccountType::FungibleFaucet,
+            AccountType::NonFungibleFaucet,
+            AccountType::RegularAccountImmutableCode,
+            AccountType::RegularAccountUpdatableCode,
+        ]
+    }
+
     /// Returns `true` if the account is a faucet.
     pub fn is_faucet(&self) -> bool {
         matches!(self, Self::FungibleFaucet | Self::NonFungibleFaucet)
@@ -97,6 +110,13 @@ pub enum AccountStorageMode {
     Private = PRIVATE,
 }
 
+impl AccountStorageMode {
+    /// Returns all account storage modes.
+    pub const fn all() -> [AccountStorageMode; 2] {
+        [AccountStorageMode::Public, AccountStorageMode::Private]
+    }
+}
+
 impl fmt::Display for AccountStorageMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -169,6 +189,9 @@ impl AccountId {
     /// Specifies a minimum number of ones for a valid account ID.
     pub const MIN_ACCOUNT_ONES: u32 = 5;
 
+    /// The length of the Crockford base32 representation of an account ID.
+    pub const BASE32_LEN: usize = 13;
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
@@ -200,6 +223,21 @@ impl AccountId {
         seed_digest[0].try_into()
     }
 
+    /// Returns true if this [AccountId] was derived from the provided seed, code commitment and
+    /// storage commitment.
+    ///
+    /// The ID is recomputed as in [AccountId::new()], so a seed which does not satisfy the
+    /// proof-of-work requirement does not verify even if the resulting ID would be equal.
+    pub fn verify_seed(
+        &self,
+        seed: Word,
+        code_commitment: Digest,
+        storage_commitment: Digest,
+    ) -> bool {
+        Self::new(seed, code_commitment, storage_commitment)
+            .is_ok_and(|account_id| account_id == *self)
+    }
+
     /// Creates a new [AccountId] without checking its validity.
     ///
     /// This function requires that the provided value is a valid [Felt] representation of an
@@ -208,6 +246,59 @@ impl AccountId {
         Self(value)
     }
 
+    /// Returns the largest valid [AccountId] with the specified account type and storage mode.
+    ///
+    /// All bits following the account metadata are set, unless this would make the ID exceed the
+    /// field modulus, in which case the largest valid field element is used.
+    pub fn max_value(account_type: AccountType, storage_mode: AccountStorageMode) -> Self {
+        let metadata = metadata_bits(account_type, storage_mode);
+        let value = (metadata | !(ACCOUNT_STORAGE_MASK | ACCOUNT_TYPE_MASK)).min(Felt::MODULUS - 1);
+
+        Self(Felt::new(value))
+    }
+
+    /// Returns the smallest valid [AccountId] with the specified account type and storage mode.
+    ///
+    /// Only the least significant bits required to reach [`AccountId::MIN_ACCOUNT_ONES`] are set in
+    /// addition to the account metadata.
+    pub fn min_value(account_type: AccountType, storage_mode: AccountStorageMode) -> Self {
+        let metadata = metadata_bits(account_type, storage_mode);
+        let missing_ones = Self::MIN_ACCOUNT_ONES.saturating_sub(metadata.count_ones());
+
+        Self(Felt::new(metadata | ((1 << missing_ones) - 1)))
+    }
+
+    /// Returns a deterministic [AccountId] and its seed for the genesis account with the specified
+    /// index, account type, storage mode, code commitment and storage commitment.
+    ///
+    /// The initial seed for the search is derived from the index as `hash(index)`, and a valid
+    /// seed is then ground from it as in [get_account_seed]. Thus, the same inputs always produce
+    /// the same ID and seed, which makes genesis block construction reproducible across nodes,
+    /// and the returned seed is accepted by [AccountId::new] so the account can be created with a
+    /// zero nonce. Distinct indices result in distinct IDs with overwhelming probability.
+    ///
+    /// # Errors
+    /// Returns an error if grinding a seed for the provided inputs fails.
+    pub fn genesis(
+        account_type: AccountType,
+        storage_mode: AccountStorageMode,
+        index: u64,
+        code_commitment: Digest,
+        storage_commitment: Digest,
+    ) -> Result<(Self, Word), AccountError> {
+        let init_seed = Hasher::hash(&index.to_le_bytes()).as_bytes();
+        let seed = get_account_seed(
+            init_seed,
+            account_type,
+            storage_mode,
+            code_commitment,
+            storage_commitment,
+        )?;
+        let id = Self::new(seed, code_commitment, storage_commitment)?;
+
+        Ok((id, seed))
+    }
+
     /// Creates a new dummy [AccountId] for testing purposes.
     #[cfg(any(feature = "testing", test))]
     pub fn new_dummy(init_seed: [u8; 32], account_type: AccountType) -> Self {
@@ -275,6 +366,15 @@ impl AccountId {
         account_type_from_u64(self.0.as_int())
     }
 
+    /// Returns the metadata nibble of this account ID, i.e., its 4 most significant bits.
+    ///
+    /// The upper two bits of the nibble encode the storage mode and the lower two bits encode the
+    /// account type. This is the nibble [AccountId::new_with_type_and_mode()] prepends to the
+    /// provided bytes.
+    pub const fn metadata(&self) -> u8 {
+        (self.0.as_int() >> ACCOUNT_TYPE_MASK_SHIFT) as u8
+    }
+
     /// Returns true if an account with this ID is a faucet (can issue assets).
     pub fn is_faucet(&self) -> bool {
         matches!(
@@ -303,6 +403,16 @@ impl AccountId {
         self.storage_mode() == AccountStorageMode::Public
     }
 
+    /// Returns true if this account ID falls into any of the provided reserved ranges.
+    ///
+    /// Each range is specified as a `(start, end)` pair of `u64` account ID values, with both
+    /// bounds inclusive. Since account IDs in this tree consist of a single field element, the
+    /// whole ID is compared against the ranges.
+    pub fn is_in_reserved_range(&self, ranges: &[(u64, u64)]) -> bool {
+        let id = self.0.as_int();
+        ranges.iter().any(|&(start, end)| (start..=end).contains(&id))
+    }
+
     /// Finds and returns a seed suitable for creating an account ID for the specified account type
     /// using the provided initial seed as a starting point.
     pub fn get_account_seed(
@@ -333,6 +443,59 @@ impl AccountId {
         format!("0x{:016x}", self.0.as_int())
     }
 
+    /// Returns the account ID encoded as a 13-character Crockford base32 string.
+    ///
+    /// The 64 bits of the ID are encoded in big-endian order, 5 bits per character, so the first
+    /// character encodes only the 4 most significant bits. The returned string uses uppercase
+    /// characters.
+    pub fn to_base32(&self) -> String {
+        let value = self.0.as_int();
+        (0..Self::BASE32_LEN)
+            .rev()
+            .map(|idx| {
+                let symbol = (value >> (5 * idx)) & 0x1f;
+                CROCKFORD_BASE32_ALPHABET[symbol as usize] as char
+            })
+            .collect()
+    }
+
+    /// Creates an account ID from its Crockford base32 representation as returned by
+    /// [AccountId::to_base32].
+    ///
+    /// Decoding is case-insensitive, and the characters `I` and `L` are decoded as `1` and `O` as
+    /// `0`, as specified by Crockford's encoding.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The string is not exactly 13 characters long, contains characters outside of the Crockford
+    ///   base32 alphabet or encodes a value which does not fit into 64 bits.
+    /// - The decoded value is not a valid account ID.
+    pub fn from_base32(base32_value: &str) -> Result<AccountId, AccountError> {
+        if base32_value.len() != Self::BASE32_LEN {
+            return Err(AccountError::Base32ParseError(format!(
+                "expected {} characters but found {}",
+                Self::BASE32_LEN,
+                base32_value.len()
+            )));
+        }
+
+        let mut value = 0u64;
+        for (idx, character) in base32_value.chars().enumerate() {
+            let symbol = decode_base32_symbol(character).ok_or_else(|| {
+                AccountError::Base32ParseError(format!("invalid base32 character {character:?}"))
+            })?;
+            // the first character only encodes the 4 most significant bits
+            if idx == 0 && symbol > 0xf {
+                return Err(AccountError::Base32ParseError(format!(
+                    "base32 value {base32_value} exceeds 64 bits"
+                )));
+            }
+            value = (value << 5) | symbol as u64;
+        }
+
+        Self::try_from(value)
+    }
+
     // UTILITY METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -467,6 +630,24 @@ impl TryFrom<u64> for AccountId {
     }
 }
 
+impl FromStr for AccountId {
+    type Err = AccountError;
+
+    /// Parses an account ID from its `0x`-prefixed, big-endian hex representation.
+    ///
+    /// This tree does not define any other textual encoding of account IDs (e.g., bech32), and
+    /// thus inputs without the `0x` prefix are rejected rather than guessed at.
+    fn from_str(input: &str) -> Result<AccountId, AccountError> {
+        if !input.starts_with("0x") {
+            return Err(AccountError::HexParseError(format!(
+                "account ID {input} is not a 0x-prefixed hex string"
+            )));
+        }
+
+        AccountId::from_hex(input)
+    }
+}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -488,6 +669,40 @@ impl Deserializable for AccountId {
     }
 }
 
+// ARBITRARY
+// ================================================================================================
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for AccountId {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    /// Generates valid account IDs of all account types and storage modes.
+    ///
+    /// Apart from the metadata nibble, all bits of the generated IDs are random. Values which do
+    /// not form a valid ID (i.e., values with too few ones or outside of the field) are rejected.
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        use proptest::{prelude::any, sample::select, strategy::Strategy};
+
+        (
+            any::<u64>(),
+            select(AccountType::all().to_vec()),
+            select(AccountStorageMode::all().to_vec()),
+        )
+            .prop_filter_map(
+                "value must be a valid account ID",
+                |(bits, account_type, storage_mode)| {
+                    let metadata = ((storage_mode as u64) << ACCOUNT_STORAGE_MASK_SHIFT)
+                        | ((account_type as u64) << ACCOUNT_TYPE_MASK_SHIFT);
+                    let value = (bits & !(ACCOUNT_STORAGE_MASK | ACCOUNT_TYPE_MASK)) | metadata;
+
+                    AccountId::try_from(value).ok()
+                },
+            )
+            .boxed()
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 fn parse_felt(bytes: &[u8]) -> Result<Felt, AccountError> {
@@ -514,6 +729,25 @@ pub(super) fn digest_pow(digest: Digest) -> u32 {
     digest.as_elements()[3].as_int().trailing_zeros()
 }
 
+/// Returns the account ID bits encoding the specified account type and storage mode.
+fn metadata_bits(account_type: AccountType, storage_mode: AccountStorageMode) -> u64 {
+    ((storage_mode as u64) << ACCOUNT_STORAGE_MASK_SHIFT)
+        | ((account_type as u64) << ACCOUNT_TYPE_MASK_SHIFT)
+}
+
+/// Returns the value of the provided Crockford base32 character, or `None` if the character is not
+/// part of the alphabet.
+fn decode_base32_symbol(character: char) -> Option<u8> {
+    match character.to_ascii_uppercase() {
+        'O' => Some(0),
+        'I' | 'L' => Some(1),
+        character => CROCKFORD_BASE32_ALPHABET
+            .iter()
+            .position(|&symbol| symbol as char == character)
+            .map(|idx| idx as u8),
+    }
+}
+
 /// Returns true if an account with this ID is a regular account.
 fn is_regular_account(account_id: u64) -> bool {
     let account_type = account_id.into();
@@ -528,8 +762,11 @@ fn is_regular_account(account_id: u64) -> bool {
 
 #[cfg(any(feature = "testing", test))]
 pub mod testing {
+    use alloc::vec::Vec;
+
     use super::{
-        AccountStorageMode, AccountType, ACCOUNT_STORAGE_MASK_SHIFT, ACCOUNT_TYPE_MASK_SHIFT,
+        AccountId, AccountStorageMode, AccountType, ACCOUNT_STORAGE_MASK_SHIFT,
+        ACCOUNT_TYPE_MASK_SHIFT,
     };
 
     // CONSTANTS
@@ -597,9 +834,43 @@ pub mod testing {
     pub const ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN_1: u64 =
         account_id(AccountType::NonFungibleFaucet, AccountStorageMode::Public, 0b0011_1111);
 
+    /// All valid account ID constants defined in this module.
+    ///
+    /// [ACCOUNT_ID_INSUFFICIENT_ONES] is intentionally excluded as it is not a valid account ID.
+    const ALL_TEST_ACCOUNT_IDS: [u64; 15] = [
+        ACCOUNT_ID_SENDER,
+        ACCOUNT_ID_OFF_CHAIN_SENDER,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN_2,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3,
+        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN,
+        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN_1,
+    ];
+
     // UTILITIES
     // --------------------------------------------------------------------------------------------
 
+    /// Returns all valid account ID constants defined in this module.
+    pub fn all_test_account_ids() -> &'static [u64] {
+        &ALL_TEST_ACCOUNT_IDS
+    }
+
+    /// Returns all valid account ID constants defined in this module as [AccountId]s.
+    pub fn all_as_account_ids() -> Vec<AccountId> {
+        all_test_account_ids()
+            .iter()
+            .map(|&id| AccountId::try_from(id).expect("test account IDs should be valid"))
+            .collect()
+    }
+
     pub const fn account_id(
         account_type: AccountType,
         storage_mode: AccountStorageMode,
@@ -620,12 +891,16 @@ pub mod testing {
 
 #[cfg(test)]
 mod tests {
+    use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+    use core::str::FromStr;
+
     use miden_crypto::utils::{Deserializable, Serializable};
 
     use super::{
-        testing::*, AccountId, AccountStorageMode, AccountType, ACCOUNT_ISFAUCET_MASK,
-        ACCOUNT_TYPE_MASK_SHIFT, FUNGIBLE_FAUCET, NON_FUNGIBLE_FAUCET,
-        REGULAR_ACCOUNT_IMMUTABLE_CODE, REGULAR_ACCOUNT_UPDATABLE_CODE,
+        get_account_seed, testing::*, AccountError, AccountId, AccountStorageMode, AccountType,
+        Digest, Felt, Word, ACCOUNT_ISFAUCET_MASK, ACCOUNT_TYPE_MASK_SHIFT, FUNGIBLE_FAUCET,
+        NON_FUNGIBLE_FAUCET, PRIVATE, PUBLIC, REGULAR_ACCOUNT_IMMUTABLE_CODE,
+        REGULAR_ACCOUNT_UPDATABLE_CODE,
     };
 
     #[test]
@@ -659,6 +934,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_account_id_from_str() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN)
+            .expect("Valid account ID");
+        assert_eq!(account_id, AccountId::from_str(&account_id.to_hex()).unwrap());
+        assert_eq!(account_id, account_id.to_hex().parse().unwrap());
+
+        // inputs which are not 0x-prefixed hex strings are rejected
+        let unprefixed = account_id.to_hex().trim_start_matches("0x").to_string();
+        assert!(matches!(AccountId::from_str(&unprefixed), Err(AccountError::HexParseError(_))));
+        assert!(matches!(AccountId::from_str("mm1qxyz"), Err(AccountError::HexParseError(_))));
+    }
+
+    #[test]
+    fn test_account_id_base32_round_trip() {
+        for account_id in all_as_account_ids() {
+            let base32 = account_id.to_base32();
+            assert_eq!(base32.len(), AccountId::BASE32_LEN);
+            assert_eq!(account_id, AccountId::from_base32(&base32).unwrap());
+            // decoding is case-insensitive
+            assert_eq!(account_id, AccountId::from_base32(&base32.to_lowercase()).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_account_id_from_base32_invalid() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let base32 = account_id.to_base32();
+
+        // U is not part of the Crockford alphabet
+        let invalid_char = format!("{}U", &base32[..AccountId::BASE32_LEN - 1]);
+        assert!(matches!(
+            AccountId::from_base32(&invalid_char),
+            Err(AccountError::Base32ParseError(_))
+        ));
+
+        // the value must have exactly 13 characters and fit into 64 bits
+        assert!(matches!(
+            AccountId::from_base32(&base32[1..]),
+            Err(AccountError::Base32ParseError(_))
+        ));
+        assert!(matches!(
+            AccountId::from_base32("ZZZZZZZZZZZZZ"),
+            Err(AccountError::Base32ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_account_id_is_in_reserved_range() {
+        let reserved = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let not_reserved =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+
+        let id = u64::from(reserved);
+        let ranges = [(0, 10), (id - 1, id + 1)];
+        assert!(reserved.is_in_reserved_range(&ranges));
+        assert!(!not_reserved.is_in_reserved_range(&ranges));
+
+        // bounds are inclusive
+        assert!(reserved.is_in_reserved_range(&[(id, id)]));
+        assert!(!reserved.is_in_reserved_range(&[]));
+    }
+
     #[test]
     fn test_account_id_serde() {
         let account_id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN)
@@ -755,13 +1094,8 @@ mod tests {
         // Use the lowest possible input to check whether the constructor satisfies
         // MIN_ACCOUNT_ONES.
         for input in [[0xff; 8], [0; 8]] {
-            for account_type in [
-                AccountType::FungibleFaucet,
-                AccountType::NonFungibleFaucet,
-                AccountType::RegularAccountImmutableCode,
-                AccountType::RegularAccountUpdatableCode,
-            ] {
-                for storage_mode in [AccountStorageMode::Private, AccountStorageMode::Public] {
+            for account_type in AccountType::all() {
+                for storage_mode in AccountStorageMode::all() {
                     let id = AccountId::new_with_type_and_mode(input, account_type, storage_mode);
                     // Do a serialization roundtrip to ensure validity.
                     AccountId::read_from_bytes(&id.to_bytes()).unwrap();
@@ -769,4 +1103,156 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_account_id_min_and_max_values() {
+        for account_type in AccountType::all() {
+            for storage_mode in AccountStorageMode::all() {
+                let min = AccountId::min_value(account_type, storage_mode);
+                let max = AccountId::max_value(account_type, storage_mode);
+
+                for id in [min, max] {
+                    let parsed = AccountId::try_from(u64::from(id)).unwrap();
+                    assert_eq!(parsed.account_type(), account_type);
+                    assert_eq!(parsed.storage_mode(), storage_mode);
+                }
+                assert!(min < max);
+
+                // the value just below the minimum has too few ones
+                assert!(AccountId::try_from(u64::from(min) - 1).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn test_all_test_account_ids_are_valid() {
+        let ids = all_as_account_ids();
+        assert_eq!(ids.len(), all_test_account_ids().len());
+
+        for (&raw, id) in all_test_account_ids().iter().zip(ids) {
+            assert_eq!(u64::from(id), raw);
+            AccountId::read_from_bytes(&id.to_bytes()).unwrap();
+        }
+
+        assert!(!all_test_account_ids().contains(&ACCOUNT_ID_INSUFFICIENT_ONES));
+    }
+
+    #[test]
+    fn test_account_id_genesis() {
+        let code_commitment = Digest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+        let storage_commitment =
+            Digest::new([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]);
+        let genesis = |account_type, storage_mode, index| {
+            AccountId::genesis(
+                account_type,
+                storage_mode,
+                index,
+                code_commitment,
+                storage_commitment,
+            )
+            .unwrap()
+        };
+
+        let accounts: Vec<(AccountId, Word)> = (0..8)
+            .map(|index| {
+                genesis(AccountType::RegularAccountUpdatableCode, AccountStorageMode::Public, index)
+            })
+            .collect();
+
+        // the same index always results in the same ID and seed
+        assert_eq!(
+            accounts[7],
+            genesis(AccountType::RegularAccountUpdatableCode, AccountStorageMode::Public, 7)
+        );
+
+        // distinct indices result in distinct IDs
+        let unique: BTreeSet<AccountId> = accounts.iter().map(|(id, _)| *id).collect();
+        assert_eq!(unique.len(), accounts.len());
+
+        for account_type in AccountType::all() {
+            for storage_mode in AccountStorageMode::all() {
+                let (id, seed) = genesis(account_type, storage_mode, 0);
+                assert_eq!(id.account_type(), account_type);
+                assert_eq!(id.storage_mode(), storage_mode);
+
+                // the returned seed is accepted by seed validation
+                assert!(id.verify_seed(seed, code_commitment, storage_commitment));
+            }
+        }
+    }
+
+    #[test]
+    fn test_account_id_verify_seed() {
+        let code_commitment = Digest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+        let storage_commitment =
+            Digest::new([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]);
+
+        let seed = get_account_seed(
+            [3; 32],
+            AccountType::RegularAccountImmutableCode,
+            AccountStorageMode::Public,
+            code_commitment,
+            storage_commitment,
+        )
+        .unwrap();
+        let account_id = AccountId::new(seed, code_commitment, storage_commitment).unwrap();
+
+        assert!(account_id.verify_seed(seed, code_commitment, storage_commitment));
+
+        // a different seed or different commitments do not verify
+        let mut wrong_seed = seed;
+        wrong_seed[0] = wrong_seed[0] + Felt::new(1);
+        assert!(!account_id.verify_seed(wrong_seed, code_commitment, storage_commitment));
+        assert!(!account_id.verify_seed(seed, storage_commitment, code_commitment));
+    }
+
+    #[test]
+    fn test_all_account_types_and_storage_modes() {
+        assert_eq!(AccountType::all().len(), 4);
+        assert_eq!(AccountStorageMode::all().len(), 2);
+
+        for account_type in AccountType::all() {
+            assert_eq!(
+                AccountType::from((account_type as u64) << ACCOUNT_TYPE_MASK_SHIFT),
+                account_type
+            );
+        }
+    }
+
+    #[test]
+    fn test_account_id_metadata() {
+        let id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        assert_eq!(id.metadata(), ((PUBLIC as u8) << 2) | FUNGIBLE_FAUCET as u8);
+
+        let id = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+        assert_eq!(id.metadata(), ((PRIVATE as u8) << 2) | REGULAR_ACCOUNT_UPDATABLE_CODE as u8);
+
+        for account_type in AccountType::all() {
+            for storage_mode in AccountStorageMode::all() {
+                let id = AccountId::new_with_type_and_mode([0xff; 8], account_type, storage_mode);
+                assert_eq!(id.metadata(), ((storage_mode as u8) << 2) | account_type as u8);
+            }
+        }
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn test_arbitrary_account_id_roundtrip(
+            account_id in proptest::prelude::any::<AccountId>()
+        ) {
+            let from_hex = AccountId::from_hex(&account_id.to_hex()).unwrap();
+            let from_bytes = AccountId::read_from_bytes(&account_id.to_bytes()).unwrap();
+            proptest::prop_assert_eq!(account_id, from_hex);
+            proptest::prop_assert_eq!(account_id, from_bytes);
+        }
+
+        #[test]
+        fn test_arbitrary_account_id_is_valid(
+            account_id in proptest::prelude::any::<AccountId>()
+        ) {
+            let parsed = AccountId::try_from(u64::from(account_id));
+            proptest::prop_assert_eq!(parsed, Ok(account_id));
+        }
+    }
 }
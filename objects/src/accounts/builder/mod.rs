@@ -87,6 +87,35 @@ impl AccountBuilder {
         self
     }
 
+    /// Checks that the configured components can be merged into an account of the configured
+    /// type, without building the account code or storage.
+    ///
+    /// This allows surfacing configuration errors before the comparatively expensive merging of
+    /// the components' MAST forests done by [`Self::build`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Any of the components does not support the set account type.
+    /// - The number of [`StorageSlot`](crate::accounts::StorageSlot)s of all components, including
+    ///   the reserved faucet slot, exceeds 255.
+    pub fn validate(&self) -> Result<(), AccountError> {
+        super::validate_components_support_account_type(&self.components, self.account_type)?;
+
+        let num_reserved_slots = usize::from(self.account_type.is_faucet());
+        let num_slots = self
+            .components
+            .iter()
+            .map(|component| component.storage_size() as usize)
+            .sum::<usize>()
+            + num_reserved_slots;
+        if num_slots > AccountStorage::MAX_NUM_STORAGE_SLOTS {
+            return Err(AccountError::StorageTooManySlots(num_slots as u64));
+        }
+
+        Ok(())
+    }
+
     /// Builds the common parts of testing and non-testing code.
     fn build_inner(
         &self,
@@ -382,4 +411,45 @@ mod tests {
             matches!(build_error, AccountError::BuildError(msg, _) if msg == "account asset vault must be empty on new accounts")
         )
     }
+
+    #[test]
+    fn account_builder_validate() {
+        let builder = Account::builder()
+            .init_seed([5; 32])
+            .with_component(CustomComponent1 { slot0: 25 });
+        assert!(builder.validate().is_ok());
+
+        // a component which only supports regular accounts cannot be added to a faucet
+        let regular_component = AccountComponent::new(CUSTOM_LIBRARY2.clone(), vec![])
+            .unwrap()
+            .with_supported_type(AccountType::RegularAccountUpdatableCode);
+        let builder = Account::builder()
+            .init_seed([5; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_component(CustomComponent1 { slot0: 25 })
+            .with_component(regular_component);
+        assert_eq!(
+            builder.validate().unwrap_err(),
+            AccountError::UnsupportedComponentForAccountType {
+                account_type: AccountType::FungibleFaucet,
+                component_index: 1,
+            }
+        );
+
+        // the reserved faucet slot counts towards the storage slot limit
+        let large_component = AccountComponent::new(
+            CUSTOM_LIBRARY2.clone(),
+            vec![StorageSlot::empty_value(); AccountStorage::MAX_NUM_STORAGE_SLOTS],
+        )
+        .unwrap()
+        .with_supports_all_types();
+        let builder = Account::builder()
+            .init_seed([5; 32])
+            .account_type(AccountType::FungibleFaucet)
+            .with_component(large_component);
+        assert_eq!(
+            builder.validate().unwrap_err(),
+            AccountError::StorageTooManySlots(AccountStorage::MAX_NUM_STORAGE_SLOTS as u64 + 1)
+        );
+    }
 }
@@ -1,4 +1,5 @@
 use alloc::{collections::BTreeSet, string::ToString, sync::Arc, vec::Vec};
+use core::ops::Range;
 
 use vm_core::mast::MastForest;
 
@@ -6,7 +7,7 @@ use super::{
     AccountError, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt,
     Hasher, Serializable,
 };
-use crate::accounts::{AccountComponent, AccountType};
+use crate::accounts::{AccountComponent, AccountStorage, AccountType};
 
 pub mod procedure;
 use procedure::AccountProcedureInfo;
@@ -77,57 +78,48 @@ impl AccountCode {
             MastForest::merge(components.iter().map(|component| component.mast_forest()))
                 .map_err(|err| AccountError::AccountCodeMergeError(err.to_string()))?;
 
-        let mut procedures = Vec::new();
-        let mut proc_root_set = BTreeSet::new();
-
-        // Slot 0 is globally reserved for faucet accounts so the accessible slots begin at 1 if
-        // there is a faucet component present.
-        let mut component_storage_offset = if account_type.is_faucet() { 1 } else { 0 };
-
-        for component in components {
-            let component_storage_size = component.storage_size();
-
-            for module in component.library().module_infos() {
-                for proc_mast_root in module.procedure_digests() {
-                    // We cannot support procedures from multiple components with the same MAST root
-                    // since storage offsets/sizes are set per MAST root. Setting them again for
-                    // procedures where the offset has already been inserted would cause that
-                    // procedure of the earlier component to write to the wrong slot.
-                    if !proc_root_set.insert(proc_mast_root) {
-                        return Err(AccountError::AccountCodeMergeError(format!(
-                            "procedure with MAST root {proc_mast_root} is present in multiple account components"
-                        )));
-                    }
-
-                    // Components that do not access storage need to have offset and size set to 0.
-                    let (storage_offset, storage_size) = if component_storage_size == 0 {
-                        (0, 0)
-                    } else {
-                        (component_storage_offset, component_storage_size)
-                    };
-
-                    // Note: Offset and size are validated in `AccountProcedureInfo::new`.
-                    procedures.push(AccountProcedureInfo::new(
-                        proc_mast_root,
-                        storage_offset,
-                        storage_size,
-                    )?);
-                }
-            }
+        let procedures = build_procedure_infos(components, account_type)?;
 
-            component_storage_offset = component_storage_offset.checked_add(component_storage_size)
-              .expect("account procedure info constructor should return an error if the addition overflows");
-        }
+        Ok(Self {
+            commitment: build_procedure_commitment(&procedures),
+            procedures,
+            mast: Arc::new(merged_mast_forest),
+        })
+    }
 
-        // make sure the number of procedures is between 1 and 256 (both inclusive)
-        if procedures.is_empty() {
-            return Err(AccountError::AccountCodeNoProcedures);
-        } else if procedures.len() > Self::MAX_NUM_PROCEDURES {
-            return Err(AccountError::AccountCodeTooManyProcedures {
-                max: Self::MAX_NUM_PROCEDURES,
-                actual: procedures.len(),
-            });
-        }
+    /// Creates a new [`AccountCode`] from the provided components' libraries, assigning to each
+    /// component the storage slots specified by the respective range in `layout`.
+    ///
+    /// Unlike [`Account::initialize_from_components`](super::Account::initialize_from_components),
+    /// which assigns storage slots to components contiguously, this allows pinning the storage
+    /// slots of a component, e.g., to keep its slot indices stable across versions. The caller is
+    /// responsible for building account storage which matches the provided layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Any of the components does not support the provided account type.
+    /// - The number of slot ranges in `layout` is not equal to the number of components.
+    /// - The length of any slot range is not equal to the storage size of the respective component.
+    /// - Any slot range includes slot 0 while the account is a faucet.
+    /// - Any two slot ranges overlap.
+    /// - The number of procedures in all merged libraries is 0 or exceeds
+    ///   [`AccountCode::MAX_NUM_PROCEDURES`].
+    /// - Two or more libraries export a procedure with the same MAST root.
+    /// - [`MastForest::merge`] fails on all libraries.
+    pub fn from_components_with_layout(
+        components: &[AccountComponent],
+        account_type: AccountType,
+        layout: &[Range<u8>],
+    ) -> Result<Self, AccountError> {
+        super::validate_components_support_account_type(components, account_type)?;
+        let storage_offsets = validate_storage_layout(components, account_type, layout)?;
+
+        let (merged_mast_forest, _) =
+            MastForest::merge(components.iter().map(|component| component.mast_forest()))
+                .map_err(|err| AccountError::AccountCodeMergeError(err.to_string()))?;
+
+        let procedures = build_procedure_infos_with_offsets(components, &storage_offsets)?;
 
         Ok(Self {
             commitment: build_procedure_commitment(&procedures),
@@ -136,6 +128,31 @@ impl AccountCode {
         })
     }
 
+    /// Returns the commitment of the [`AccountCode`] which would be built from the provided
+    /// components, without merging the components' MAST forests.
+    ///
+    /// This can be used to compute the code commitment required to derive an account ID without
+    /// building the account code itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Any of the components does not support the provided account type.
+    /// - The number of procedures in all components is 0 or exceeds
+    ///   [`AccountCode::MAX_NUM_PROCEDURES`].
+    /// - Two or more components export a procedure with the same MAST root.
+    /// - The number of [`StorageSlot`](crate::accounts::StorageSlot)s of a component or of all
+    ///   components exceeds 255.
+    pub fn commitment_from_components(
+        components: &[AccountComponent],
+        account_type: AccountType,
+    ) -> Result<Digest, AccountError> {
+        super::validate_components_support_account_type(components, account_type)?;
+        let procedures = build_procedure_infos(components, account_type)?;
+
+        Ok(build_procedure_commitment(&procedures))
+    }
+
     /// Returns a new [AccountCode] deserialized from the provided bytes.
     ///
     /// # Errors
@@ -196,6 +213,15 @@ impl AccountCode {
         self.procedures.iter().any(|procedure| procedure.mast_root() == &mast_root)
     }
 
+    /// Returns true if this account code exports the same set of procedures as `other`.
+    ///
+    /// Unlike comparing commitments, this ignores the order of the procedures and their storage
+    /// offsets and sizes, and compares only the MAST roots of the exported procedures.
+    pub fn same_interface(&self, other: &AccountCode) -> bool {
+        let roots: BTreeSet<Digest> = self.procedure_roots().collect();
+        roots == other.procedure_roots().collect::<BTreeSet<_>>()
+    }
+
     /// Returns information about the procedure at the specified index.
     ///
     /// # Panics
@@ -299,6 +325,158 @@ fn procedures_as_elements(procedures: &[AccountProcedureInfo]) -> Vec<Felt> {
         .collect()
 }
 
+/// Builds the procedure information for the procedures exported by the provided components.
+///
+/// Storage offsets are assigned to each component sequentially, with slot 0 reserved for faucet
+/// accounts.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The number of procedures in all components is 0 or exceeds
+///   [`AccountCode::MAX_NUM_PROCEDURES`].
+/// - Two or more components export a procedure with the same MAST root.
+/// - The storage offset or size of any procedure is invalid.
+fn build_procedure_infos(
+    components: &[AccountComponent],
+    account_type: AccountType,
+) -> Result<Vec<AccountProcedureInfo>, AccountError> {
+    // Slot 0 is globally reserved for faucet accounts so the accessible slots begin at 1 if
+    // there is a faucet component present.
+    let mut component_storage_offset: u8 = if account_type.is_faucet() { 1 } else { 0 };
+
+    let mut storage_offsets = Vec::with_capacity(components.len());
+    for component in components {
+        let component_storage_size = component.storage_size();
+        storage_offsets.push(component_storage_offset);
+
+        component_storage_offset = component_storage_offset
+            .checked_add(component_storage_size)
+            .ok_or(AccountError::StorageOffsetOutOfBounds {
+                max: AccountStorage::MAX_NUM_STORAGE_SLOTS as u8,
+                actual: component_storage_offset as u16 + component_storage_size as u16,
+            })?;
+    }
+
+    build_procedure_infos_with_offsets(components, &storage_offsets)
+}
+
+/// Validates the provided storage `layout` against the provided components and returns the
+/// storage offset of each component.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The number of slot ranges is not equal to the number of components.
+/// - The length of any slot range is not equal to the storage size of the respective component.
+/// - Any slot range includes slot 0 while the account is a faucet.
+/// - Any two slot ranges overlap.
+fn validate_storage_layout(
+    components: &[AccountComponent],
+    account_type: AccountType,
+    layout: &[Range<u8>],
+) -> Result<Vec<u8>, AccountError> {
+    if layout.len() != components.len() {
+        return Err(AccountError::InvalidComponentStorageLayout(format!(
+            "expected {} slot ranges but {} were provided",
+            components.len(),
+            layout.len()
+        )));
+    }
+
+    for (component_index, (component, range)) in components.iter().zip(layout).enumerate() {
+        if range.len() != component.storage_size() as usize {
+            return Err(AccountError::InvalidComponentStorageLayout(format!(
+                "component {component_index} has {} storage slots but was assigned {} slots",
+                component.storage_size(),
+                range.len()
+            )));
+        }
+
+        if account_type.is_faucet() && !range.is_empty() && range.start == 0 {
+            return Err(AccountError::InvalidComponentStorageLayout(format!(
+                "component {component_index} was assigned slot 0 which is reserved for faucets"
+            )));
+        }
+    }
+
+    // check that no two non-empty ranges overlap by sorting them by their start
+    let mut ranges: Vec<&Range<u8>> = layout.iter().filter(|range| !range.is_empty()).collect();
+    ranges.sort_by_key(|range| range.start);
+    for pair in ranges.windows(2) {
+        if pair[0].end > pair[1].start {
+            return Err(AccountError::InvalidComponentStorageLayout(format!(
+                "slot ranges {:?} and {:?} overlap",
+                pair[0], pair[1]
+            )));
+        }
+    }
+
+    Ok(layout.iter().map(|range| range.start).collect())
+}
+
+/// Builds the procedure information for the procedures exported by the provided components using
+/// the provided storage offset for each component.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The number of procedures in all components is 0 or exceeds
+///   [`AccountCode::MAX_NUM_PROCEDURES`].
+/// - Two or more components export a procedure with the same MAST root.
+/// - The storage offset or size of any procedure is invalid.
+fn build_procedure_infos_with_offsets(
+    components: &[AccountComponent],
+    storage_offsets: &[u8],
+) -> Result<Vec<AccountProcedureInfo>, AccountError> {
+    let mut procedures = Vec::new();
+    let mut proc_root_set = BTreeSet::new();
+
+    for (component, &component_storage_offset) in components.iter().zip(storage_offsets) {
+        let component_storage_size = component.storage_size();
+
+        for module in component.library().module_infos() {
+            for proc_mast_root in module.procedure_digests() {
+                // We cannot support procedures from multiple components with the same MAST root
+                // since storage offsets/sizes are set per MAST root. Setting them again for
+                // procedures where the offset has already been inserted would cause that
+                // procedure of the earlier component to write to the wrong slot.
+                if !proc_root_set.insert(proc_mast_root) {
+                    return Err(AccountError::AccountCodeMergeError(format!(
+                        "procedure with MAST root {proc_mast_root} is present in multiple account components"
+                    )));
+                }
+
+                // Components that do not access storage need to have offset and size set to 0.
+                let (storage_offset, storage_size) = if component_storage_size == 0 {
+                    (0, 0)
+                } else {
+                    (component_storage_offset, component_storage_size)
+                };
+
+                // Note: Offset and size are validated in `AccountProcedureInfo::new`.
+                procedures.push(AccountProcedureInfo::new(
+                    proc_mast_root,
+                    storage_offset,
+                    storage_size,
+                )?);
+            }
+        }
+    }
+
+    // make sure the number of procedures is between 1 and 256 (both inclusive)
+    if procedures.is_empty() {
+        return Err(AccountError::AccountCodeNoProcedures);
+    } else if procedures.len() > AccountCode::MAX_NUM_PROCEDURES {
+        return Err(AccountError::AccountCodeTooManyProcedures {
+            max: AccountCode::MAX_NUM_PROCEDURES,
+            actual: procedures.len(),
+        });
+    }
+
+    Ok(procedures)
+}
+
 /// Computes the commitment to the given procedures
 fn build_procedure_commitment(procedures: &[AccountProcedureInfo]) -> Digest {
     let elements = procedures_as_elements(procedures);
@@ -310,6 +488,7 @@ fn build_procedure_commitment(procedures: &[AccountProcedureInfo]) -> Digest {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
 
     use assembly::Assembler;
     use vm_core::Word;
@@ -369,4 +548,101 @@ mod tests {
 
         assert!(matches!(err, AccountError::StorageOffsetOutOfBounds { actual: 256, .. }))
     }
+
+    #[test]
+    fn test_commitment_from_components() {
+        let library1 = Assembler::default().assemble_library(["export.foo add end"]).unwrap();
+        let library2 = Assembler::default().assemble_library(["export.bar sub end"]).unwrap();
+
+        let component1 =
+            AccountComponent::new(library1, vec![StorageSlot::Value(Word::default()); 2])
+                .unwrap()
+                .with_supports_all_types();
+        let component2 = AccountComponent::new(library2, vec![]).unwrap().with_supports_all_types();
+        let components = [component1, component2];
+
+        for account_type in [AccountType::RegularAccountUpdatableCode, AccountType::FungibleFaucet]
+        {
+            let commitment =
+                AccountCode::commitment_from_components(&components, account_type).unwrap();
+            let code = AccountCode::from_components(&components, account_type).unwrap();
+            assert_eq!(commitment, code.commitment());
+        }
+    }
+
+    #[test]
+    fn test_account_code_from_components_with_layout() {
+        let library1 = Assembler::default().assemble_library(["export.foo add end"]).unwrap();
+        let library2 = Assembler::default().assemble_library(["export.bar sub end"]).unwrap();
+
+        let component1 =
+            AccountComponent::new(library1, vec![StorageSlot::Value(Word::default()); 2])
+                .unwrap()
+                .with_supports_all_types();
+        let component2 = AccountComponent::new(library2, vec![StorageSlot::Value(Word::default())])
+            .unwrap()
+            .with_supports_all_types();
+        let components = [component1, component2];
+        let account_type = AccountType::RegularAccountUpdatableCode;
+
+        // the second component is placed before the first one, leaving slot 1 unused
+        let code =
+            AccountCode::from_components_with_layout(&components, account_type, &[2..4, 0..1])
+                .unwrap();
+        let offsets: Vec<(u8, u8)> = code
+            .procedures()
+            .iter()
+            .map(|procedure| (procedure.storage_offset(), procedure.storage_size()))
+            .collect();
+        assert_eq!(offsets, [(2, 2), (0, 1)]);
+
+        // overlapping slot ranges are rejected
+        let err =
+            AccountCode::from_components_with_layout(&components, account_type, &[0..2, 1..2])
+                .unwrap_err();
+        assert!(matches!(err, AccountError::InvalidComponentStorageLayout(_)));
+
+        // slot ranges which do not match the component storage sizes are rejected
+        let err =
+            AccountCode::from_components_with_layout(&components, account_type, &[0..1, 1..2])
+                .unwrap_err();
+        assert!(matches!(err, AccountError::InvalidComponentStorageLayout(_)));
+
+        // slot 0 cannot be assigned to components of faucet accounts
+        let err = AccountCode::from_components_with_layout(
+            &components,
+            AccountType::FungibleFaucet,
+            &[1..3, 0..1],
+        )
+        .unwrap_err();
+        assert!(matches!(err, AccountError::InvalidComponentStorageLayout(_)));
+    }
+
+    #[test]
+    fn test_account_code_same_interface() {
+        let library1 = Assembler::default().assemble_library(["export.foo add end"]).unwrap();
+        let library2 = Assembler::default().assemble_library(["export.bar sub end"]).unwrap();
+
+        let component1 =
+            AccountComponent::new(library1, vec![StorageSlot::Value(Word::default()); 2])
+                .unwrap()
+                .with_supports_all_types();
+        let component2 = AccountComponent::new(library2, vec![StorageSlot::Value(Word::default())])
+            .unwrap()
+            .with_supports_all_types();
+        let components = [component1.clone(), component2];
+        let account_type = AccountType::RegularAccountUpdatableCode;
+
+        // the same procedures with a different storage layout result in a different commitment
+        let code = AccountCode::from_components(&components, account_type).unwrap();
+        let relocated =
+            AccountCode::from_components_with_layout(&components, account_type, &[2..4, 0..1])
+                .unwrap();
+        assert_ne!(code.commitment(), relocated.commitment());
+        assert!(code.same_interface(&relocated));
+
+        let other = AccountCode::from_components(&[component1], account_type).unwrap();
+        assert!(!code.same_interface(&other));
+        assert!(!other.same_interface(&code));
+    }
 }
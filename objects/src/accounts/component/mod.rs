@@ -5,7 +5,7 @@ use vm_processor::MastForest;
 
 use crate::{
     accounts::{AccountType, StorageSlot},
-    AccountError,
+    AccountError, Digest,
 };
 
 /// An [`AccountComponent`] defines a [`Library`] of code and the initial value and types of
@@ -28,6 +28,7 @@ pub struct AccountComponent {
     pub(super) library: Library,
     pub(super) storage_slots: Vec<StorageSlot>,
     pub(super) supported_types: BTreeSet<AccountType>,
+    pub(super) dependencies: BTreeSet<Digest>,
 }
 
 impl AccountComponent {
@@ -57,6 +58,7 @@ impl AccountComponent {
             library: code,
             storage_slots,
             supported_types: BTreeSet::new(),
+            dependencies: BTreeSet::new(),
         })
     }
 
@@ -117,6 +119,11 @@ impl AccountComponent {
         self.supported_types.contains(&account_type)
     }
 
+    /// Returns a reference to the MAST roots of the procedures this component depends on.
+    pub fn dependencies(&self) -> &BTreeSet<Digest> {
+        &self.dependencies
+    }
+
     // MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -138,14 +145,18 @@ impl AccountComponent {
         self
     }
 
+    /// Declares that this component calls the procedure with the given `mast_root`, which must be
+    /// exported by another component of the same account.
+    ///
+    /// Dependencies are checked when the account code is built from a set of components.
+    pub fn with_dependency(mut self, mast_root: Digest) -> Self {
+        self.dependencies.insert(mast_root);
+        self
+    }
+
     /// Sets the [`AccountType`]s supported by this component to all account types.
     pub fn with_supports_all_types(mut self) -> Self {
-        self.supported_types.extend([
-            AccountType::FungibleFaucet,
-            AccountType::NonFungibleFaucet,
-            AccountType::RegularAccountImmutableCode,
-            AccountType::RegularAccountUpdatableCode,
-        ]);
+        self.supported_types.extend(AccountType::all());
         self
     }
 }
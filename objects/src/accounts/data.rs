@@ -12,7 +12,7 @@ use super::{
     super::utils::serde::{
         ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
     },
-    Account, AuthSecretKey, Word,
+    Account, AccountError, AccountStorageMode, AuthSecretKey, Word,
 };
 
 // ACCOUNT DATA
@@ -39,6 +39,19 @@ impl AccountData {
         }
     }
 
+    /// Checks that the account described by this account data has the expected storage mode.
+    ///
+    /// # Errors
+    /// Returns an error if the storage mode of the account is not equal to `expected`.
+    pub fn assert_storage_mode(&self, expected: AccountStorageMode) -> Result<(), AccountError> {
+        let found = self.account.id().storage_mode();
+        if found != expected {
+            return Err(AccountError::StorageModeMismatch { expected, found });
+        }
+
+        Ok(())
+    }
+
     #[cfg(feature = "std")]
     /// Serialises and writes binary AccountData to specified file
     pub fn write(&self, filepath: impl AsRef<Path>) -> io::Result<()> {
@@ -106,9 +119,10 @@ mod tests {
     use crate::{
         accounts::{
             account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, storage,
-            Account, AccountCode, AccountId, AuthSecretKey, Felt, Word,
+            Account, AccountCode, AccountId, AccountStorageMode, AuthSecretKey, Felt, Word,
         },
         assets::AssetVault,
+        AccountError,
     };
 
     fn build_account_data() -> AccountData {
@@ -139,6 +153,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_assert_storage_mode() {
+        let account_data = build_account_data();
+        assert!(account_data.assert_storage_mode(AccountStorageMode::Public).is_ok());
+        assert_eq!(
+            account_data.assert_storage_mode(AccountStorageMode::Private),
+            Err(AccountError::StorageModeMismatch {
+                expected: AccountStorageMode::Private,
+                found: AccountStorageMode::Public,
+            })
+        );
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_serde_file() {
@@ -1,10 +1,10 @@
-use alloc::string::ToString;
+use alloc::{collections::BTreeMap, string::ToString};
 
 use super::{
     Account, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, Serializable,
     Word, ZERO,
 };
-use crate::AccountDeltaError;
+use crate::{assets::Asset, AccountDeltaError, Digest};
 
 mod storage;
 pub use storage::{AccountStorageDelta, StorageMapDelta};
@@ -90,12 +90,154 @@ impl AccountDelta {
         self.nonce
     }
 
+    /// Returns the number of assets added to the account vault by this delta.
+    ///
+    /// Fungible assets are counted once per faucet whose balance is increased by this delta.
+    pub fn num_assets_added(&self) -> usize {
+        self.vault.added_fungible().count() + self.vault.added_non_fungible().count()
+    }
+
+    /// Returns the number of assets removed from the account vault by this delta.
+    ///
+    /// Fungible assets are counted once per faucet whose balance is decreased by this delta.
+    pub fn num_assets_removed(&self) -> usize {
+        self.vault.removed_fungible().count() + self.vault.removed_non_fungible().count()
+    }
+
+    /// Returns the number of storage slots updated by this delta.
+    pub fn num_storage_changes(&self) -> usize {
+        self.storage.values().len() + self.storage.maps().len()
+    }
+
+    /// Returns the amount by which this delta increases the nonce relative to the provided
+    /// starting nonce.
+    ///
+    /// Returns `None` if the delta does not set a nonce, or if the new nonce is smaller than
+    /// `from`.
+    pub fn nonce_increase(&self, from: Felt) -> Option<u64> {
+        self.nonce.and_then(|nonce| nonce.as_int().checked_sub(from.as_int()))
+    }
+
+    /// Checks whether this delta is internally consistent.
+    ///
+    /// Deltas built via [AccountDelta::new()] from storage deltas which were mutated directly
+    /// (e.g., via [AccountStorageDelta::set_item()]) or built via
+    /// [AccountStorageDelta::from_iters()] are not guaranteed to be consistent, and thus should
+    /// be validated before being applied.
+    ///
+    /// Note that the vault delta cannot contain an asset which is both added and removed, since it
+    /// tracks a single net balance change per fungible faucet and a single action per non-fungible
+    /// asset.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - A storage slot is updated both as a value slot (including cleared slots) and as a map.
+    /// - The storage or the vault were updated, but the nonce was not.
+    /// - The nonce is set to 0.
+    pub fn validate(&self) -> Result<(), AccountDeltaError> {
+        self.storage.validate()?;
+        validate_nonce(self.nonce, &self.storage, &self.vault)?;
+
+        if self.nonce == Some(ZERO) {
+            return Err(AccountDeltaError::InconsistentNonceUpdate(
+                "zero nonce for an account delta".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Converts this storage delta into individual delta components.
     pub fn into_parts(self) -> (AccountStorageDelta, AccountVaultDelta, Option<Felt>) {
         (self.storage, self.vault, self.nonce)
     }
 }
 
+// ACCOUNT DELTA BUILDER
+// ================================================================================================
+
+/// A builder for [AccountDelta] which accumulates storage and vault changes incrementally.
+///
+/// All validation is deferred to [AccountDeltaBuilder::build()], which enforces the same rules as
+/// [AccountDelta::new()].
+#[derive(Clone, Debug, Default)]
+pub struct AccountDeltaBuilder {
+    values: BTreeMap<u8, Word>,
+    maps: BTreeMap<u8, StorageMapDelta>,
+    vault: AccountVaultDelta,
+    nonce: Option<Felt>,
+}
+
+impl AccountDeltaBuilder {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [AccountDeltaBuilder] without any changes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the value of the storage slot at the specified index.
+    pub fn set_item(mut self, slot_index: u8, new_slot_value: Word) -> Self {
+        self.values.insert(slot_index, new_slot_value);
+        self
+    }
+
+    /// Sets the value of the specified key in the storage map at the specified index.
+    pub fn set_map_item(mut self, slot_index: u8, key: Digest, new_value: Word) -> Self {
+        self.maps.entry(slot_index).or_default().insert(key, new_value);
+        self
+    }
+
+    /// Sets the storage map delta for the storage map at the specified index, replacing any
+    /// previously accumulated changes for that slot.
+    pub fn set_map(mut self, slot_index: u8, map_delta: StorageMapDelta) -> Self {
+        self.maps.insert(slot_index, map_delta);
+        self
+    }
+
+    /// Tracks the addition of the provided asset to the account vault.
+    ///
+    /// # Errors
+    /// Returns an error if the resulting vault delta is invalid (e.g., an amount overflow).
+    pub fn add_asset(mut self, asset: Asset) -> Result<Self, AccountDeltaError> {
+        self.vault.add_asset(asset)?;
+        Ok(self)
+    }
+
+    /// Tracks the removal of the provided asset from the account vault.
+    ///
+    /// # Errors
+    /// Returns an error if the resulting vault delta is invalid (e.g., an amount overflow).
+    pub fn remove_asset(mut self, asset: Asset) -> Result<Self, AccountDeltaError> {
+        self.vault.remove_asset(asset)?;
+        Ok(self)
+    }
+
+    /// Sets the new nonce of the account.
+    pub fn nonce(mut self, nonce: Felt) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    // BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds an [AccountDelta] from the accumulated changes.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The same storage slot was updated both as a value slot and as a map slot.
+    /// - Storage or vault were updated, but the nonce was either not set or set to 0.
+    pub fn build(self) -> Result<AccountDelta, AccountDeltaError> {
+        let storage = AccountStorageDelta::new(self.values, self.maps)?;
+        AccountDelta::new(storage, self.vault, self.nonce)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum AccountUpdateDetails {
     /// Account is private (no on-chain state change).
@@ -259,14 +401,88 @@ mod tests {
     use super::{AccountDelta, AccountStorageDelta, AccountVaultDelta};
     use crate::{
         accounts::{
-            account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
-            delta::AccountUpdateDetails, Account, AccountCode, AccountId, AccountStorage,
-            AccountType, StorageMapDelta,
+            account_id::testing::{
+                ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+            },
+            delta::AccountUpdateDetails,
+            Account, AccountCode, AccountId, AccountStorage, AccountType, StorageMapDelta,
         },
         assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
-        ONE, ZERO,
+        testing::{constants::NON_FUNGIBLE_ASSET_DATA, storage::build_assets},
+        AccountDeltaError, ONE, ZERO,
     };
 
+    #[test]
+    fn account_delta_nonce_increase() {
+        let storage_delta = AccountStorageDelta::from_iters([1], [], []);
+        let vault_delta = AccountVaultDelta::default();
+
+        let delta =
+            AccountDelta::new(storage_delta, vault_delta.clone(), Some(Felt::new(2))).unwrap();
+        assert_eq!(delta.nonce_increase(ONE), Some(1));
+        assert_eq!(delta.nonce_increase(Felt::new(3)), None);
+
+        let delta = AccountDelta::new(AccountStorageDelta::default(), vault_delta, None).unwrap();
+        assert_eq!(delta.nonce_increase(ONE), None);
+    }
+
+    #[test]
+    fn account_delta_counts() {
+        let (asset_0, asset_1) = build_assets();
+        let non_fungible = NonFungibleAsset::mock(
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            &NON_FUNGIBLE_ASSET_DATA,
+        );
+
+        let map_delta = StorageMapDelta::from_iters([], [([ONE; 4], [ONE; 4])]);
+        let storage_delta = AccountStorageDelta::from_iters([1], [(2, [ONE; 4])], [(3, map_delta)]);
+        let vault_delta = AccountVaultDelta::from_iters([asset_0, non_fungible], [asset_1]);
+        let delta = AccountDelta::new(storage_delta, vault_delta, Some(ONE)).unwrap();
+
+        assert_eq!(delta.num_assets_added(), 2);
+        assert_eq!(delta.num_assets_removed(), 1);
+        assert_eq!(delta.num_storage_changes(), 3);
+
+        let delta = AccountDelta::default();
+        assert_eq!(delta.num_assets_added(), 0);
+        assert_eq!(delta.num_assets_removed(), 0);
+        assert_eq!(delta.num_storage_changes(), 0);
+    }
+
+    #[test]
+    fn account_delta_validate() {
+        let storage_delta = AccountStorageDelta::from_iters([1], [(2, [ONE; 4])], []);
+        let delta =
+            AccountDelta::new(storage_delta, AccountVaultDelta::default(), Some(ONE)).unwrap();
+        assert!(delta.validate().is_ok());
+        assert!(AccountDelta::default().validate().is_ok());
+
+        // a cleared slot is also updated as a map
+        let map_delta = StorageMapDelta::from_iters([], [([ONE; 4], [ONE; 4])]);
+        let storage_delta = AccountStorageDelta::from_iters([1], [], [(1, map_delta.clone())]);
+        let delta =
+            AccountDelta::new(storage_delta, AccountVaultDelta::default(), Some(ONE)).unwrap();
+        assert_eq!(delta.validate(), Err(AccountDeltaError::DuplicateStorageItemUpdate(1)));
+
+        // an updated value slot is also updated as a map
+        let mut storage_delta = AccountStorageDelta::default();
+        storage_delta.set_item(3, [ONE; 4]);
+        storage_delta.set_map_item(3, [ONE; 4].into(), [ONE; 4]);
+        let delta =
+            AccountDelta::new(storage_delta, AccountVaultDelta::default(), Some(ONE)).unwrap();
+        assert_eq!(delta.validate(), Err(AccountDeltaError::DuplicateStorageItemUpdate(3)));
+
+        // the nonce is set to zero
+        let delta = AccountDelta::new(
+            AccountStorageDelta::default(),
+            AccountVaultDelta::default(),
+            Some(ZERO),
+        )
+        .unwrap();
+        assert!(matches!(delta.validate(), Err(AccountDeltaError::InconsistentNonceUpdate(_))));
+    }
+
     #[test]
     fn account_delta_nonce_validation() {
         // empty delta
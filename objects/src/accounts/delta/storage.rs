@@ -50,6 +50,20 @@ impl AccountStorageDelta {
         &self.maps
     }
 
+    /// Returns an iterator over the updates to storage maps in this delta, keyed by the index of
+    /// the updated slot, in ascending order of slot indexes.
+    pub fn map_deltas(&self) -> impl Iterator<Item = (u8, &StorageMapDelta)> {
+        self.maps.iter().map(|(&slot_index, map_delta)| (slot_index, map_delta))
+    }
+
+    /// Returns an iterator over the updates to value slots in this delta, keyed by the index of the
+    /// updated slot, in ascending order of slot indexes.
+    ///
+    /// Cleared slots are included with [EMPTY_WORD] as their new value.
+    pub fn value_updates(&self) -> impl Iterator<Item = (u8, &Word)> {
+        self.values.iter().map(|(&slot_index, value)| (slot_index, value))
+    }
+
     /// Returns true if storage delta contains no updates.
     pub fn is_empty(&self) -> bool {
         self.values.is_empty() && self.maps.is_empty()
@@ -86,7 +100,7 @@ impl AccountStorageDelta {
     ///
     /// # Errors:
     /// - Any of the updated slot is referenced from both maps (e.g., updated twice).
-    fn validate(&self) -> Result<(), AccountDeltaError> {
+    pub(super) fn validate(&self) -> Result<(), AccountDeltaError> {
         for slot in self.maps.keys() {
             if self.values.contains_key(slot) {
                 return Err(AccountDeltaError::DuplicateStorageItemUpdate(*slot as usize));
@@ -129,6 +143,8 @@ impl AccountStorageDelta {
 
 impl Serializable for AccountStorageDelta {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        // slots and map keys are stored in BTreeMaps, so items are always written sorted by slot
+        // index (and map deltas by key), regardless of the order in which they were inserted
         let cleared: Vec<u8> = self.cleared_slots().collect();
         let updated: Vec<(&u8, &Word)> = self.updated_slots().collect();
 
@@ -304,9 +320,13 @@ impl Deserializable for StorageMapDelta {
 
 #[cfg(test)]
 mod tests {
-    use super::{AccountStorageDelta, Deserializable, Serializable};
+    use alloc::vec::Vec;
+
+    use super::{AccountStorageDelta, Deserializable, Digest, Serializable, EMPTY_WORD};
     use crate::{
-        accounts::StorageMapDelta, testing::storage::AccountStorageDeltaBuilder, ONE, ZERO,
+        accounts::{AccountStorage, StorageMap, StorageMapDelta, StorageSlot},
+        testing::storage::AccountStorageDeltaBuilder,
+        ONE, ZERO,
     };
 
     #[test]
@@ -344,6 +364,23 @@ mod tests {
         assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
     }
 
+    #[test]
+    fn test_iterate_storage_delta() {
+        let map_delta = StorageMapDelta::from_iters([], [([ONE; 4], [ONE; 4])]);
+        let delta = AccountStorageDeltaBuilder::default()
+            .add_cleared_items([3])
+            .add_updated_values([(1, [ONE; 4])])
+            .add_updated_maps([(2, map_delta.clone()), (0, StorageMapDelta::default())])
+            .build()
+            .unwrap();
+
+        let map_deltas: Vec<_> = delta.map_deltas().collect();
+        assert_eq!(map_deltas, [(0, &StorageMapDelta::default()), (2, &map_delta)]);
+
+        let value_updates: Vec<_> = delta.value_updates().collect();
+        assert_eq!(value_updates, [(1, &[ONE; 4]), (3, &EMPTY_WORD)]);
+    }
+
     #[test]
     fn test_is_empty() {
         let storage_delta = AccountStorageDelta::default();
@@ -384,6 +421,33 @@ mod tests {
         assert_eq!(deserialized, storage_delta);
     }
 
+    #[test]
+    fn test_serialization_is_order_independent() {
+        let key_0 = Digest::from([ONE, ZERO, ZERO, ZERO]);
+        let key_1 = Digest::from([ZERO, ONE, ZERO, ZERO]);
+        let value = [ONE, ONE, ONE, ONE];
+
+        let mut delta_a = AccountStorageDelta::default();
+        delta_a.set_item(1, EMPTY_WORD);
+        delta_a.set_item(4, EMPTY_WORD);
+        delta_a.set_item(2, value);
+        delta_a.set_item(5, value);
+        delta_a.set_map_item(3, key_0, value);
+        delta_a.set_map_item(3, key_1, EMPTY_WORD);
+        delta_a.set_map_item(6, key_1, value);
+
+        let mut delta_b = AccountStorageDelta::default();
+        delta_b.set_map_item(6, key_1, value);
+        delta_b.set_map_item(3, key_1, EMPTY_WORD);
+        delta_b.set_map_item(3, key_0, value);
+        delta_b.set_item(5, value);
+        delta_b.set_item(4, EMPTY_WORD);
+        delta_b.set_item(2, value);
+        delta_b.set_item(1, EMPTY_WORD);
+
+        assert_eq!(delta_a.to_bytes(), delta_b.to_bytes());
+    }
+
     #[test]
     fn test_serde_storage_map_delta() {
         let storage_map_delta = StorageMapDelta::default();
@@ -455,4 +519,44 @@ mod tests {
 
         assert_eq!(delta_x, expected);
     }
+
+    #[test]
+    fn merge_then_apply_equals_sequential_application() {
+        let word = |x: u64| [vm_core::Felt::new(x), ZERO, ZERO, ZERO];
+        let storage = AccountStorage::new(vec![
+            StorageSlot::Value(word(1)),
+            StorageSlot::Value(word(2)),
+            StorageSlot::Map(StorageMap::with_entries([(word(10).into(), word(100))]).unwrap()),
+        ])
+        .unwrap();
+
+        let first = AccountStorageDeltaBuilder::default()
+            .add_cleared_items([0])
+            .add_updated_values([(1, word(3))])
+            .add_updated_maps([(2, StorageMapDelta::from_iters([], [(word(10), word(101))]))])
+            .build()
+            .unwrap();
+        // slot 0 is written after being cleared, slot 1 is overwritten and the map is updated
+        // key-wise
+        let second = AccountStorageDeltaBuilder::default()
+            .add_updated_values([(0, word(4)), (1, word(5))])
+            .add_updated_maps([(
+                2,
+                StorageMapDelta::from_iters([word(10)], [(word(11), word(111))]),
+            )])
+            .build()
+            .unwrap();
+
+        let mut sequential = storage.clone();
+        sequential.apply_delta(&first).unwrap();
+        sequential.apply_delta(&second).unwrap();
+
+        let mut merged = first;
+        merged.merge(second).unwrap();
+        let mut merged_storage = storage;
+        merged_storage.apply_delta(&merged).unwrap();
+
+        assert_eq!(merged_storage.commitment(), sequential.commitment());
+        assert_eq!(merged_storage.get_item(0).unwrap(), Digest::from(word(4)));
+    }
 }
@@ -78,6 +78,30 @@ impl AccountVaultDelta {
         self.non_fungible.merge(other.non_fungible)?;
         self.fungible.merge(other.fungible)
     }
+
+    /// Returns an iterator over the fungible assets added to the vault by this delta.
+    ///
+    /// Each returned asset carries the net amount added for its faucet.
+    pub fn added_fungible(&self) -> impl Iterator<Item = FungibleAsset> + '_ {
+        self.fungible.filter_by_sign(true)
+    }
+
+    /// Returns an iterator over the fungible assets removed from the vault by this delta.
+    ///
+    /// Each returned asset carries the net amount removed for its faucet.
+    pub fn removed_fungible(&self) -> impl Iterator<Item = FungibleAsset> + '_ {
+        self.fungible.filter_by_sign(false)
+    }
+
+    /// Returns an iterator over the non-fungible assets added to the vault by this delta.
+    pub fn added_non_fungible(&self) -> impl Iterator<Item = NonFungibleAsset> + '_ {
+        self.non_fungible.filter_by_action(NonFungibleDeltaAction::Add)
+    }
+
+    /// Returns an iterator over the non-fungible assets removed from the vault by this delta.
+    pub fn removed_non_fungible(&self) -> impl Iterator<Item = NonFungibleAsset> + '_ {
+        self.non_fungible.filter_by_action(NonFungibleDeltaAction::Remove)
+    }
 }
 
 #[cfg(any(feature = "testing", test))]
@@ -119,32 +143,16 @@ impl AccountVaultDelta {
 
     /// Returns an iterator over the added assets in this delta.
     pub fn added_assets(&self) -> impl Iterator<Item = crate::assets::Asset> + '_ {
-        use crate::assets::{Asset, FungibleAsset, NonFungibleAsset};
-        self.fungible
-            .0
-            .iter()
-            .filter(|&(_, &value)| value >= 0)
-            .map(|(&faucet_id, &diff)| {
-                Asset::Fungible(FungibleAsset::new(faucet_id, diff.unsigned_abs()).unwrap())
-            })
-            .chain(self.non_fungible.filter_by_action(NonFungibleDeltaAction::Add).map(|key| {
-                Asset::NonFungible(unsafe { NonFungibleAsset::new_unchecked(key.into()) })
-            }))
+        self.added_fungible()
+            .map(Asset::Fungible)
+            .chain(self.added_non_fungible().map(Asset::NonFungible))
     }
 
     /// Returns an iterator over the removed assets in this delta.
     pub fn removed_assets(&self) -> impl Iterator<Item = crate::assets::Asset> + '_ {
-        use crate::assets::{Asset, FungibleAsset, NonFungibleAsset};
-        self.fungible
-            .0
-            .iter()
-            .filter(|&(_, &value)| value < 0)
-            .map(|(&faucet_id, &diff)| {
-                Asset::Fungible(FungibleAsset::new(faucet_id, diff.unsigned_abs()).unwrap())
-            })
-            .chain(self.non_fungible.filter_by_action(NonFungibleDeltaAction::Remove).map(|key| {
-                Asset::NonFungible(unsafe { NonFungibleAsset::new_unchecked(key.into()) })
-            }))
+        self.removed_fungible()
+            .map(Asset::Fungible)
+            .chain(self.removed_non_fungible().map(Asset::NonFungible))
     }
 }
 
@@ -268,6 +276,20 @@ impl FungibleAssetDelta {
         Ok(())
     }
 
+    /// Returns an iterator over the assets with a non-negative balance change if `added` is true,
+    /// or over the assets with a negative balance change otherwise.
+    ///
+    /// Zero-amount entries, which are inserted when a zero-amount asset is added to an empty
+    /// entry, are reported as added.
+    fn filter_by_sign(&self, added: bool) -> impl Iterator<Item = FungibleAsset> + '_ {
+        self.0.iter().filter(move |&(_, &amount)| (amount >= 0) == added).map(
+            |(&faucet_id, &amount)| {
+                FungibleAsset::new(faucet_id, amount.unsigned_abs())
+                    .expect("fungible asset delta should not exceed the maximum asset amount")
+            },
+        )
+    }
+
     /// Checks whether this vault delta is valid.
     ///
     /// # Errors
@@ -464,6 +486,8 @@ pub enum NonFungibleDeltaAction {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use super::{AccountVaultDelta, Deserializable, Serializable};
     use crate::{
         accounts::{
@@ -487,6 +511,46 @@ mod tests {
         assert_eq!(deserialized, delta);
     }
 
+    #[test]
+    fn test_added_and_removed_assets() {
+        let faucet_0 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+        let nf_faucet = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+
+        let fungible_0 = FungibleAsset::new(faucet_0, 100).unwrap();
+        let fungible_1 = FungibleAsset::new(faucet_1, 50).unwrap();
+        let non_fungible_0 =
+            NonFungibleAsset::new(&NonFungibleAssetDetails::new(nf_faucet, vec![1]).unwrap())
+                .unwrap();
+        let non_fungible_1 =
+            NonFungibleAsset::new(&NonFungibleAssetDetails::new(nf_faucet, vec![2]).unwrap())
+                .unwrap();
+
+        let delta = AccountVaultDelta::from_iters(
+            [fungible_0.into(), non_fungible_0.into()],
+            [fungible_1.into(), non_fungible_1.into()],
+        );
+
+        assert_eq!(delta.added_fungible().collect::<Vec<_>>(), [fungible_0]);
+        assert_eq!(delta.removed_fungible().collect::<Vec<_>>(), [fungible_1]);
+        assert_eq!(delta.added_non_fungible().collect::<Vec<_>>(), [non_fungible_0]);
+        assert_eq!(delta.removed_non_fungible().collect::<Vec<_>>(), [non_fungible_1]);
+    }
+
+    #[test]
+    fn test_zero_amount_fungible_delta_is_added() {
+        let faucet = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let zero_asset = FungibleAsset::new(faucet, 0).unwrap();
+
+        // adding a zero-amount asset inserts a zero entry into the delta
+        let delta = AccountVaultDelta::from_iters([zero_asset.into()], []);
+
+        assert_eq!(delta.added_fungible().collect::<Vec<_>>(), [zero_asset]);
+        assert_eq!(delta.removed_fungible().count(), 0);
+        assert_eq!(delta.added_assets().collect::<Vec<_>>(), [Asset::Fungible(zero_asset)]);
+        assert_eq!(delta.removed_assets().count(), 0);
+    }
+
     #[test]
     fn test_is_empty_account_vault() {
         let faucet = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
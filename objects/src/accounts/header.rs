@@ -101,3 +101,38 @@ impl From<&Account> for AccountHeader {
         }
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use vm_core::Felt;
+
+    use super::{hash_account, AccountHeader};
+    use crate::{
+        accounts::StorageSlot,
+        testing::storage::{build_account, build_assets},
+    };
+
+    #[test]
+    fn test_account_hash_from_header_fields() {
+        let (asset_0, _) = build_assets();
+        let account = build_account(
+            vec![asset_0],
+            Felt::new(3),
+            vec![StorageSlot::Value([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)])],
+        );
+        let header = AccountHeader::from(&account);
+
+        let hash = hash_account(
+            header.id(),
+            header.nonce(),
+            header.vault_root(),
+            header.storage_commitment(),
+            header.code_commitment(),
+        );
+        assert_eq!(hash, account.hash());
+        assert_eq!(hash, header.hash());
+    }
+}
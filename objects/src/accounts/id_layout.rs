@@ -0,0 +1,68 @@
+//! Bit layout of an [AccountId](super::AccountId).
+//!
+//! An account ID is a single field element. Its two most significant bits encode the account
+//! storage mode and the next two bits encode the account type. The remaining bits are derived from
+//! the account seed digest.
+//!
+//! These constants allow code which assembles or inspects account IDs from raw bits to do so
+//! without duplicating the layout.
+
+use super::account_id::{
+    ACCOUNT_ISFAUCET_MASK, ACCOUNT_STORAGE_MASK, ACCOUNT_STORAGE_MASK_SHIFT, ACCOUNT_TYPE_MASK,
+    ACCOUNT_TYPE_MASK_SHIFT,
+};
+
+/// Depth of the account tree; account IDs are used directly as leaf indexes in this tree.
+pub const ACCOUNT_TREE_DEPTH: u8 = crate::ACCOUNT_TREE_DEPTH;
+
+/// Position of the least significant bit of the account type.
+pub const TYPE_SHIFT: u64 = ACCOUNT_TYPE_MASK_SHIFT;
+
+/// Mask selecting the two account type bits.
+pub const TYPE_MASK: u64 = ACCOUNT_TYPE_MASK;
+
+/// Mask selecting the account type bit which is set for faucet accounts.
+pub const FAUCET_MASK: u64 = ACCOUNT_ISFAUCET_MASK;
+
+/// Position of the least significant bit of the account storage mode.
+pub const STORAGE_MODE_SHIFT: u64 = ACCOUNT_STORAGE_MASK_SHIFT;
+
+/// Mask selecting the two account storage mode bits.
+pub const STORAGE_MODE_MASK: u64 = ACCOUNT_STORAGE_MASK;
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::{AccountId, AccountStorageMode, AccountType};
+
+    #[test]
+    fn test_id_layout_round_trip() {
+        for account_type in [
+            AccountType::RegularAccountImmutableCode,
+            AccountType::RegularAccountUpdatableCode,
+            AccountType::FungibleFaucet,
+            AccountType::NonFungibleFaucet,
+        ] {
+            for storage_mode in [AccountStorageMode::Public, AccountStorageMode::Private] {
+                let raw = (storage_mode as u64) << STORAGE_MODE_SHIFT
+                    | (account_type as u64) << TYPE_SHIFT
+                    | 0b1_1111;
+
+                let id = AccountId::try_from(raw).unwrap();
+                assert_eq!(id.account_type(), account_type);
+                assert_eq!(id.storage_mode(), storage_mode);
+                assert_eq!(id.is_faucet(), raw & FAUCET_MASK != 0);
+                assert_eq!((u64::from(id) & TYPE_MASK) >> TYPE_SHIFT, account_type as u64);
+                assert_eq!(
+                    (u64::from(id) & STORAGE_MODE_MASK) >> STORAGE_MODE_SHIFT,
+                    storage_mode as u64
+                );
+            }
+        }
+
+        assert_eq!(ACCOUNT_TREE_DEPTH as u32, u64::BITS);
+    }
+}
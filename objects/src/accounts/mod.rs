@@ -1,7 +1,13 @@
+use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+
 use crate::{
     assets::AssetVault,
-    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
-    AccountError, Digest, Felt, Hasher, Word, ZERO,
+    crypto::merkle::LeafIndex,
+    utils::{
+        par_map_chunks,
+        serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    },
+    AccountError, Digest, Felt, Hasher, Word, ACCOUNT_TREE_DEPTH, ZERO,
 };
 
 pub mod account_id;
@@ -10,6 +16,8 @@ pub use account_id::{
     ACCOUNT_TYPE_MASK_SHIFT,
 };
 
+pub mod id_layout;
+
 pub mod auth;
 
 pub use auth::AuthSecretKey;
@@ -25,7 +33,7 @@ pub use component::AccountComponent;
 
 pub mod delta;
 pub use delta::{
-    AccountDelta, AccountStorageDelta, AccountVaultDelta, FungibleAssetDelta,
+    AccountDelta, AccountDeltaBuilder, AccountStorageDelta, AccountVaultDelta, FungibleAssetDelta,
     NonFungibleAssetDelta, NonFungibleDeltaAction, StorageMapDelta,
 };
 
@@ -33,7 +41,10 @@ mod seed;
 pub use seed::{get_account_seed, get_account_seed_single};
 
 mod storage;
-pub use storage::{AccountStorage, AccountStorageHeader, StorageMap, StorageSlot, StorageSlotType};
+pub use storage::{
+    compute_storage_commitment, AccountStorage, AccountStorageHeader, StorageMap, StorageSlot,
+    StorageSlotType,
+};
 
 mod header;
 pub use header::AccountHeader;
@@ -101,6 +112,43 @@ impl Account {
         Self { id, vault, storage, code, nonce }
     }
 
+    /// Returns a copy of this account with the ID derived from the specified seed and the
+    /// commitments to this account's code and storage.
+    ///
+    /// The vault, storage and code of the returned account are the same as in this account, and
+    /// its nonce is set to ZERO. This is useful when instantiating many accounts from a template
+    /// account.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Deriving account ID from the specified seed fails.
+    /// - The account type or the storage mode of the derived ID differ from those of this account's
+    ///   ID.
+    pub fn with_new_id(&self, seed: Word) -> Result<Self, AccountError> {
+        let id = AccountId::new(seed, self.code.commitment(), self.storage.commitment())?;
+
+        if id.account_type() != self.id.account_type() {
+            return Err(AccountError::AccountTypeMismatch {
+                expected: self.id.account_type(),
+                found: id.account_type(),
+            });
+        }
+        if id.storage_mode() != self.id.storage_mode() {
+            return Err(AccountError::StorageModeMismatch {
+                expected: self.id.storage_mode(),
+                found: id.storage_mode(),
+            });
+        }
+
+        Ok(Self {
+            id,
+            vault: self.vault.clone(),
+            storage: self.storage.clone(),
+            code: self.code.clone(),
+            nonce: ZERO,
+        })
+    }
+
     /// Creates an account's [`AccountCode`] and [`AccountStorage`] from the provided components.
     ///
     /// This merges all libraries of the components into a single
@@ -135,6 +183,7 @@ impl Account {
     ///
     /// Returns an error if:
     /// - Any of the components does not support `account_type`.
+    /// - A procedure any of the components depends on is not exported by any of the components.
     /// - The number of procedures in all merged libraries is 0 or exceeds
     ///   [`AccountCode::MAX_NUM_PROCEDURES`].
     /// - Two or more libraries export a procedure with the same MAST root.
@@ -145,6 +194,7 @@ impl Account {
         components: &[AccountComponent],
     ) -> Result<(AccountCode, AccountStorage), AccountError> {
         validate_components_support_account_type(components, account_type)?;
+        validate_component_dependencies(components)?;
 
         let code = AccountCode::from_components_unchecked(components, account_type)?;
         let storage = AccountStorage::from_components(components, account_type)?;
@@ -175,6 +225,12 @@ impl Account {
         )
     }
 
+    /// Returns the index of the leaf of this account in the account tree together with the value
+    /// stored in this leaf, i.e., the hash of this account.
+    pub fn tree_entry(&self) -> (LeafIndex<ACCOUNT_TREE_DEPTH>, Digest) {
+        (self.id.into(), self.hash())
+    }
+
     /// Returns hash of this account as used for the initial account state hash in transaction
     /// proofs.
     ///
@@ -227,6 +283,72 @@ impl Account {
         self.id.is_faucet()
     }
 
+    /// Returns true if this faucet account has issued any assets.
+    ///
+    /// For a fungible faucet this checks whether the total issuance recorded in its reserved
+    /// storage slot is greater than zero. For a non-fungible faucet this checks whether the map of
+    /// issued assets in its reserved storage slot is non-empty.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - This account is not a faucet.
+    /// - The reserved storage slot of this faucet is missing or has the wrong type.
+    pub fn has_issued_assets(&self) -> Result<bool, AccountError> {
+        let expected_type =
+            self.faucet_data_slot_type().ok_or(AccountError::NotAFaucet(self.id))?;
+        self.storage.check_slot_type(AccountStorage::FAUCET_DATA_SLOT, expected_type)?;
+
+        let faucet_data = &self.storage.slots()[AccountStorage::FAUCET_DATA_SLOT as usize];
+        Ok(match faucet_data {
+            // the faucet data of a fungible faucet is [0, 0, 0, total_issuance]
+            StorageSlot::Value(value) => value[3] != ZERO,
+            StorageSlot::Map(_) => !faucet_data.is_default(),
+        })
+    }
+
+    /// Returns true if the storage slot reserved for faucet data still holds the default value of
+    /// its type, i.e., an empty word for fungible faucets and an empty map for non-fungible
+    /// faucets.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - This account is not a faucet.
+    /// - The reserved storage slot of this faucet is missing or has the wrong type.
+    pub fn is_reserved_slot_default(&self) -> Result<bool, AccountError> {
+        let expected_type =
+            self.faucet_data_slot_type().ok_or(AccountError::NotAFaucet(self.id))?;
+        self.storage.check_slot_type(AccountStorage::FAUCET_DATA_SLOT, expected_type)?;
+
+        Ok(self.storage.slots()[AccountStorage::FAUCET_DATA_SLOT as usize].is_default())
+    }
+
+    /// Returns the RpoFalcon512 public key used to authenticate transactions against this account.
+    ///
+    /// This assumes the authentication component is the first component of the account, and thus
+    /// that the public key is stored in the first storage slot not reserved for faucet data, i.e.,
+    /// slot 0 for regular accounts and slot 1 for faucets.
+    ///
+    /// # Errors
+    /// Returns an error if the storage slot of the public key is missing or is not a value slot.
+    pub fn rpo_falcon_public_key(&self) -> Result<Word, AccountError> {
+        let index = u8::from(self.faucet_data_slot_type().is_some());
+        self.storage.check_slot_type(index, StorageSlotType::Value)?;
+
+        Ok(self.storage.slots()[index as usize].value())
+    }
+
+    /// Returns true if this account is equal to the `other` account when their vaults are ignored,
+    /// i.e., if the two accounts have the same ID, storage, code, and nonce.
+    ///
+    /// This can be used to check that a migration of account code or storage does not affect
+    /// anything but the intended parts of the account.
+    pub fn eq_ignoring_vault(&self, other: &Account) -> bool {
+        self.id == other.id
+            && self.storage == other.storage
+            && self.code == other.code
+            && self.nonce == other.nonce
+    }
+
     /// Returns true if this is a regular account.
     pub fn is_regular_account(&self) -> bool {
         self.id.is_regular_account()
@@ -242,6 +364,68 @@ impl Account {
         self.nonce == ZERO
     }
 
+    /// Checks the internal consistency of this account.
+    ///
+    /// Accounts built via [Account::initialize_from_components()] are always consistent, but an
+    /// account obtained through other means (e.g., deserialized from untrusted bytes) may not be.
+    /// See also [Account::read_from_bytes_validated()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account code does not have any procedures.
+    /// - Any procedure of the account code can access storage slots which do not exist in the
+    ///   account storage.
+    /// - The account is a faucet and any procedure can access the reserved storage slot.
+    /// - The account is a faucet and its reserved storage slot is missing or has the wrong type for
+    ///   the faucet type.
+    pub fn validate(&self) -> Result<(), AccountError> {
+        if self.code.num_procedures() == 0 {
+            return Err(AccountError::AccountCodeNoProcedures);
+        }
+
+        // the storage layout of the code must agree with the storage of the account and with the
+        // account type: slot 0 is reserved for faucets and inaccessible to their procedures
+        let num_slots = self.storage.slots().len();
+        let first_accessible_slot = if self.is_faucet() { 1 } else { 0 };
+        for procedure in self.code.procedures().iter().filter(|proc| proc.storage_size() > 0) {
+            let storage_end = procedure.storage_offset() as u16 + procedure.storage_size() as u16;
+            if storage_end as usize > num_slots {
+                return Err(AccountError::StorageOffsetOutOfBounds {
+                    max: num_slots as u8,
+                    actual: storage_end,
+                });
+            }
+
+            if procedure.storage_offset() < first_accessible_slot {
+                return Err(AccountError::InvalidComponentStorageLayout(format!(
+                    "procedure {} of {:?} account can access reserved storage slot 0",
+                    procedure.mast_root(),
+                    self.account_type()
+                )));
+            }
+        }
+
+        if let Some(expected_type) = self.faucet_data_slot_type() {
+            self.storage.check_slot_type(AccountStorage::FAUCET_DATA_SLOT, expected_type)?;
+        }
+
+        Ok(())
+    }
+
+    /// Deserializes an account from the provided bytes and checks its internal consistency via
+    /// [Account::validate()].
+    ///
+    /// # Errors
+    /// Returns an error if deserialization fails or the deserialized account is not consistent.
+    pub fn read_from_bytes_validated(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let account = Self::read_from_bytes(bytes)?;
+        account
+            .validate()
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        Ok(account)
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -254,24 +438,67 @@ impl Account {
     /// - Applying storage sub-delta to the storage of this account fails.
     /// - The nonce specified in the provided delta smaller than or equal to the current account
     ///   nonce.
+    ///
+    /// If an error is returned, this account is left unchanged.
     pub fn apply_delta(&mut self, delta: &AccountDelta) -> Result<(), AccountError> {
-        // update vault; we don't check vault delta validity here because `AccountDelta` can contain
-        // only valid vault deltas
-        self.vault
-            .apply_delta(delta.vault())
-            .map_err(AccountError::AssetVaultUpdateError)?;
+        // make sure the nonce can be updated before modifying anything
+        if let Some(nonce) = delta.nonce() {
+            if self.nonce.as_int() >= nonce.as_int() {
+                return Err(AccountError::NonceNotMonotonicallyIncreasing {
+                    current: self.nonce.as_int(),
+                    new: nonce.as_int(),
+                });
+            }
+        }
 
-        // update storage
+        // compute the updated vault; we don't check vault delta validity here because
+        // `AccountDelta` can contain only valid vault deltas
+        let mut vault = self.vault.clone();
+        vault.apply_delta(delta.vault()).map_err(AccountError::AssetVaultUpdateError)?;
+
+        // update storage; storage is left unchanged if the storage delta cannot be applied
         self.storage.apply_delta(delta.storage())?;
 
-        // update nonce
+        // nothing can fail from this point on, so commit the vault and nonce updates
+        self.vault = vault;
         if let Some(nonce) = delta.nonce() {
-            self.set_nonce(nonce)?;
+            self.nonce = nonce;
         }
 
         Ok(())
     }
 
+    /// Applies the provided storage delta to the storage of this account and returns the indices
+    /// of the slots touched by the delta in ascending order.
+    ///
+    /// Together with [Account::apply_vault_delta()] and [Account::set_nonce()], this allows
+    /// applying an [AccountDelta] in stages.
+    ///
+    /// # Errors
+    /// Returns an error if applying the delta to the storage of this account fails. In this case,
+    /// the storage is left unchanged.
+    pub fn apply_storage_delta(
+        &mut self,
+        delta: &AccountStorageDelta,
+    ) -> Result<Vec<u8>, AccountError> {
+        self.storage.apply_delta(delta)
+    }
+
+    /// Applies the provided vault delta to the vault of this account.
+    ///
+    /// Together with [Account::apply_storage_delta()] and [Account::set_nonce()], this allows
+    /// applying an [AccountDelta] in stages.
+    ///
+    /// # Errors
+    /// Returns an error if applying the delta to the vault of this account fails. In this case,
+    /// the vault is left unchanged.
+    pub fn apply_vault_delta(&mut self, delta: &AccountVaultDelta) -> Result<(), AccountError> {
+        let mut vault = self.vault.clone();
+        vault.apply_delta(delta).map_err(AccountError::AssetVaultUpdateError)?;
+        self.vault = vault;
+        Ok(())
+    }
+
     /// Sets the nonce of this account to the specified nonce value.
     ///
     /// # Errors
@@ -291,6 +518,51 @@ impl Account {
         Ok(())
     }
 
+    // FRAMED SERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Serializes this account into the specified target, prefixed with the length of the
+    /// serialized account as a `u32`.
+    ///
+    /// This allows multiple accounts to be written into a shared stream and read back one at a
+    /// time via [Account::read_framed()].
+    ///
+    /// # Panics
+    /// Panics if the serialized account is longer than `u32::MAX` bytes.
+    pub fn write_framed<W: ByteWriter>(&self, target: &mut W) {
+        let bytes = self.to_bytes();
+        let len = u32::try_from(bytes.len()).expect("serialized account is too large");
+        target.write_u32(len);
+        target.write_bytes(&bytes);
+    }
+
+    /// Reads an account written by [Account::write_framed()] from the specified source.
+    ///
+    /// Exactly the number of bytes given by the length prefix is consumed from the source.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The source does not contain as many bytes as the length prefix specifies.
+    /// - The framed bytes are not a valid account, or the account does not consume all of them.
+    pub fn read_framed<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let len = source.read_u32()? as usize;
+        let bytes = source.read_slice(len)?;
+        Self::read_from_bytes(bytes)
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the expected type of the storage slot reserved for faucet data, or None if this
+    /// account is not a faucet.
+    fn faucet_data_slot_type(&self) -> Option<StorageSlotType> {
+        match self.account_type() {
+            AccountType::FungibleFaucet => Some(StorageSlotType::Value),
+            AccountType::NonFungibleFaucet => Some(StorageSlotType::Map),
+            _ => None,
+        }
+    }
+
     // TEST HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -360,6 +632,44 @@ pub fn hash_account(
     Hasher::hash_elements(&elements)
 }
 
+/// Returns the code commitment and the storage commitment of an account of the specified type
+/// built from the provided components, in that order.
+///
+/// The commitments are the same as those of the [`AccountCode`] and [`AccountStorage`] returned by
+/// [`Account::initialize_from_components`], but the MAST forests of the components are not merged.
+/// This allows computing the commitments required by [`AccountId::new`] without constructing the
+/// account.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Any of the components does not support `account_type`.
+/// - A procedure any of the components depends on is not exported by any of the components.
+/// - The number of procedures in all components is 0 or exceeds
+///   [`AccountCode::MAX_NUM_PROCEDURES`].
+/// - Two or more components export a procedure with the same MAST root.
+/// - The number of [`StorageSlot`]s of all components exceeds 255.
+pub fn compute_commitments(
+    components: &[AccountComponent],
+    account_type: AccountType,
+) -> Result<(Digest, Digest), AccountError> {
+    validate_component_dependencies(components)?;
+
+    let code_commitment = AccountCode::commitment_from_components(components, account_type)?;
+    let storage_commitment =
+        AccountStorage::from_components(components, account_type)?.commitment();
+
+    Ok((code_commitment, storage_commitment))
+}
+
+/// Computes the hashes of the provided accounts.
+///
+/// The hashes are returned in the same order as the input accounts. With the `concurrent` feature
+/// enabled, the work is split across multiple threads.
+pub fn hash_many(accounts: &[Account]) -> Vec<Digest> {
+    par_map_chunks(accounts, Account::hash)
+}
+
 /// Validates that all `components` support the given `account_type`.
 fn validate_components_support_account_type(
     components: &[AccountComponent],
@@ -377,27 +687,60 @@ fn validate_components_support_account_type(
     Ok(())
 }
 
+/// Validates that every procedure any of the `components` depends on is exported by one of the
+/// `components`.
+fn validate_component_dependencies(components: &[AccountComponent]) -> Result<(), AccountError> {
+    let exported_roots: BTreeSet<Digest> = components
+        .iter()
+        .flat_map(|component| component.library().module_infos())
+        .flat_map(|module| module.procedure_digests().collect::<Vec<_>>())
+        .collect();
+
+    for component in components {
+        if let Some(&missing) =
+            component.dependencies().iter().find(|root| !exported_roots.contains(root))
+        {
+            return Err(AccountError::UnresolvedComponentDependency(missing));
+        }
+    }
+
+    Ok(())
+}
+
 // TESTS
 // ================================================================================================
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use assembly::Assembler;
     use miden_crypto::{
-        utils::{Deserializable, Serializable},
+        utils::{ByteReader, Deserializable, DeserializationError, Serializable, SliceReader},
         Felt, Word,
     };
     use vm_processor::Digest;
 
-    use super::{AccountDelta, AccountStorageDelta, AccountVaultDelta};
+    use super::{AccountDelta, AccountDeltaBuilder, AccountStorageDelta, AccountVaultDelta};
     use crate::{
         accounts::{
-            Account, AccountComponent, AccountType, StorageMap, StorageMapDelta, StorageSlot,
+            account_id::testing::{
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+                ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+            },
+            compute_commitments, get_account_seed_single, hash_many, Account, AccountCode,
+            AccountComponent, AccountId, AccountStorage, AccountStorageMode, AccountType,
+            StorageMap, StorageMapDelta, StorageSlot,
         },
-        testing::storage::{
-            build_account, build_account_delta, build_assets, AccountStorageDeltaBuilder,
+        assets::{AssetVault, NonFungibleAsset},
+        crypto::merkle::LeafIndex,
+        testing::{
+            constants::NON_FUNGIBLE_ASSET_DATA,
+            storage::{
+                build_account, build_account_delta, build_assets, AccountStorageDeltaBuilder,
+            },
         },
-        AccountError,
+        AccountError, ZERO,
     };
 
     #[test]
@@ -413,6 +756,100 @@ mod tests {
         assert_eq!(deserialized, account);
     }
 
+    #[test]
+    fn test_serde_account_framed() {
+        let (asset_0, asset_1) = build_assets();
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let account_0 = build_account(vec![asset_0], Felt::new(1), vec![StorageSlot::Value(word)]);
+        let account_1 = build_account(vec![asset_1], Felt::new(2), vec![]);
+
+        let mut buffer: Vec<u8> = vec![];
+        account_0.write_framed(&mut buffer);
+        account_1.write_framed(&mut buffer);
+
+        let mut reader = SliceReader::new(&buffer);
+        assert_eq!(Account::read_framed(&mut reader).unwrap(), account_0);
+        assert_eq!(Account::read_framed(&mut reader).unwrap(), account_1);
+        assert!(!reader.has_more_bytes());
+    }
+
+    #[test]
+    fn test_hash_many() {
+        let (asset_0, asset_1) = build_assets();
+        let accounts: Vec<Account> = (0..10)
+            .map(|nonce| {
+                let assets = if nonce % 2 == 0 { vec![asset_0] } else { vec![asset_1] };
+                build_account(assets, Felt::new(nonce), vec![])
+            })
+            .collect();
+
+        let expected: Vec<Digest> = accounts.iter().map(Account::hash).collect();
+        assert_eq!(hash_many(&accounts), expected);
+        assert!(hash_many(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_account_tree_entry() {
+        let (asset_0, _) = build_assets();
+        let account = build_account(
+            vec![asset_0],
+            Felt::new(1),
+            vec![StorageSlot::Value([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)])],
+        );
+
+        let (leaf_index, value) = account.tree_entry();
+        assert_eq!(leaf_index, LeafIndex::from(account.id()));
+        assert_eq!(value, account.hash());
+    }
+
+    #[test]
+    fn test_account_with_new_id() {
+        let (asset_0, _) = build_assets();
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let account = build_account(vec![asset_0], Felt::new(3), vec![StorageSlot::Value(word)]);
+        let grind_seed = |account_type, storage_mode| {
+            get_account_seed_single(
+                [7; 32],
+                account_type,
+                storage_mode,
+                account.code().commitment(),
+                account.storage().commitment(),
+            )
+            .unwrap()
+        };
+
+        let seed = grind_seed(AccountType::RegularAccountImmutableCode, AccountStorageMode::Public);
+        let new_account = account.with_new_id(seed).unwrap();
+
+        assert_ne!(new_account.id(), account.id());
+        assert_eq!(new_account.id().account_type(), account.id().account_type());
+        assert_eq!(new_account.id().storage_mode(), account.id().storage_mode());
+        assert_eq!(new_account.nonce(), Felt::new(0));
+        assert_eq!(new_account.code().commitment(), account.code().commitment());
+        assert_eq!(new_account.storage().commitment(), account.storage().commitment());
+        assert_eq!(new_account.vault(), account.vault());
+
+        // a seed ground for a different account type or storage mode is rejected
+        let seed = grind_seed(AccountType::FungibleFaucet, AccountStorageMode::Public);
+        assert_eq!(
+            account.with_new_id(seed).unwrap_err(),
+            AccountError::AccountTypeMismatch {
+                expected: AccountType::RegularAccountImmutableCode,
+                found: AccountType::FungibleFaucet,
+            }
+        );
+
+        let seed =
+            grind_seed(AccountType::RegularAccountImmutableCode, AccountStorageMode::Private);
+        assert_eq!(
+            account.with_new_id(seed).unwrap_err(),
+            AccountError::StorageModeMismatch {
+                expected: AccountStorageMode::Public,
+                found: AccountStorageMode::Private,
+            }
+        );
+    }
+
     #[test]
     fn test_serde_account_delta() {
         let final_nonce = Felt::new(2);
@@ -498,6 +935,119 @@ mod tests {
         assert_eq!(account, final_account);
     }
 
+    #[test]
+    fn account_delta_applied_in_parts() {
+        let (asset_0, asset_1) = build_assets();
+        let storage_slots = vec![
+            StorageSlot::Value([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+            StorageSlot::Value([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]),
+        ];
+        let account = build_account(vec![asset_0], Felt::new(1), storage_slots);
+
+        let final_nonce = Felt::new(2);
+        let storage_delta = AccountStorageDeltaBuilder::default()
+            .add_cleared_items([0])
+            .add_updated_values([(1, [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)])])
+            .build()
+            .unwrap();
+        let account_delta =
+            build_account_delta(vec![asset_1], vec![asset_0], final_nonce, storage_delta);
+
+        let mut expected = account.clone();
+        expected.apply_delta(&account_delta).unwrap();
+
+        let mut staged = account;
+        let updated_slots = staged.apply_storage_delta(account_delta.storage()).unwrap();
+        assert_eq!(updated_slots, vec![0, 1]);
+        staged.apply_vault_delta(account_delta.vault()).unwrap();
+        staged.set_nonce(final_nonce).unwrap();
+
+        assert_eq!(staged, expected);
+    }
+
+    #[test]
+    fn account_delta_builder_matches_manual_construction() {
+        let (asset_0, asset_1) = build_assets();
+        let map_key = Digest::new([Felt::new(101), Felt::new(102), Felt::new(103), Felt::new(104)]);
+        let map_value = [Felt::new(9_u64), Felt::new(10_u64), Felt::new(11_u64), Felt::new(12_u64)];
+        let final_nonce = Felt::new(2);
+
+        // build the delta manually, as in `valid_account_delta_is_correctly_applied`
+        let updated_map = StorageMapDelta::from_iters([], [(map_key.into(), map_value)]);
+        let storage_delta = AccountStorageDeltaBuilder::default()
+            .add_cleared_items([0])
+            .add_updated_values([(1, [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)])])
+            .add_updated_maps([(2, updated_map)])
+            .build()
+            .unwrap();
+        let expected =
+            build_account_delta(vec![asset_1], vec![asset_0], final_nonce, storage_delta);
+
+        // build the same delta incrementally
+        let account_delta = AccountDeltaBuilder::new()
+            .set_item(0, Word::default())
+            .set_item(1, [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)])
+            .set_map_item(2, map_key, map_value)
+            .add_asset(asset_1)
+            .unwrap()
+            .remove_asset(asset_0)
+            .unwrap()
+            .nonce(final_nonce)
+            .build()
+            .unwrap();
+
+        assert_eq!(account_delta, expected);
+
+        // a non-empty delta without a nonce update is rejected
+        assert!(AccountDeltaBuilder::new().add_asset(asset_1).unwrap().build().is_err());
+
+        // a value and a map update for the same slot are rejected
+        assert!(AccountDeltaBuilder::new()
+            .set_item(2, Word::default())
+            .set_map_item(2, map_key, map_value)
+            .nonce(final_nonce)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn failed_account_delta_leaves_account_unchanged() {
+        let init_nonce = Felt::new(1);
+        let (asset_0, asset_1) = build_assets();
+        let mut account =
+            build_account(vec![asset_0], init_nonce, vec![StorageSlot::Value(Word::default())]);
+        let original = account.clone();
+
+        // the vault sub-delta is valid, but the storage sub-delta updates a map in a value slot
+        let updated_map = StorageMapDelta::from_iters(
+            [],
+            [([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)], [Felt::new(5); 4])],
+        );
+        let storage_delta = AccountStorageDeltaBuilder::default()
+            .add_updated_maps([(0, updated_map)])
+            .build()
+            .unwrap();
+        let account_delta =
+            build_account_delta(vec![asset_1], vec![asset_0], Felt::new(2), storage_delta);
+
+        assert!(account.apply_delta(&account_delta).is_err());
+        assert_eq!(account, original);
+
+        // the vault sub-delta is invalid as the removed asset is not in the vault
+        let account_delta = build_account_delta(
+            vec![],
+            vec![asset_1],
+            Felt::new(2),
+            AccountStorageDeltaBuilder::default()
+                .add_updated_values([(0, [Felt::new(1); 4])])
+                .build()
+                .unwrap(),
+        );
+
+        assert!(account.apply_delta(&account_delta).is_err());
+        assert_eq!(account, original);
+    }
+
     #[test]
     #[should_panic]
     fn valid_account_delta_with_unchanged_nonce() {
@@ -612,4 +1162,243 @@ mod tests {
 
         assert!(matches!(err, AccountError::AccountCodeMergeError(_)))
     }
+
+    /// Tests that dependencies declared by a component must be exported by one of the components
+    /// used to initialize the account.
+    #[test]
+    fn test_account_component_dependencies() {
+        let library1 = Assembler::default().assemble_library(["export.foo add end"]).unwrap();
+        let library2 = Assembler::default().assemble_library(["export.bar mul end"]).unwrap();
+
+        let foo_root = library1.module_infos().next().unwrap().procedure_digests().next().unwrap();
+        let unknown_root = Digest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+
+        let component1 = AccountComponent::new(library1, vec![]).unwrap().with_supports_all_types();
+        let component2 = AccountComponent::new(library2, vec![]).unwrap().with_supports_all_types();
+
+        // the dependency of the second component is exported by the first one
+        Account::initialize_from_components(
+            AccountType::RegularAccountUpdatableCode,
+            &[component1.clone(), component2.clone().with_dependency(foo_root)],
+        )
+        .unwrap();
+
+        // the dependency of the second component is not exported by any component
+        let err = Account::initialize_from_components(
+            AccountType::RegularAccountUpdatableCode,
+            &[component1, component2.with_dependency(unknown_root)],
+        )
+        .unwrap_err();
+
+        assert_eq!(err, AccountError::UnresolvedComponentDependency(unknown_root));
+    }
+
+    /// Builds a faucet with the specified ID whose storage consists only of the reserved slot.
+    fn build_faucet(account_id: u64, reserved_slot: StorageSlot) -> Account {
+        Account::from_parts(
+            AccountId::try_from(account_id).unwrap(),
+            AssetVault::default(),
+            AccountStorage::new(vec![reserved_slot]).unwrap(),
+            AccountCode::mock(),
+            Felt::new(1),
+        )
+    }
+
+    #[test]
+    fn test_account_has_issued_assets() {
+        // fungible faucet
+        let faucet = build_faucet(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, StorageSlot::empty_value());
+        assert!(!faucet.has_issued_assets().unwrap());
+
+        let faucet_data = [ZERO, ZERO, ZERO, Felt::new(100)];
+        let faucet =
+            build_faucet(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, StorageSlot::Value(faucet_data));
+        assert!(faucet.has_issued_assets().unwrap());
+
+        // non-fungible faucet
+        let faucet =
+            build_faucet(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, StorageSlot::empty_map());
+        assert!(!faucet.has_issued_assets().unwrap());
+
+        let asset = NonFungibleAsset::mock(
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            &NON_FUNGIBLE_ASSET_DATA,
+        );
+        let issued_assets =
+            StorageMap::with_entries([(Word::from(asset).into(), asset.into())]).unwrap();
+        let faucet =
+            build_faucet(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, StorageSlot::Map(issued_assets));
+        assert!(faucet.has_issued_assets().unwrap());
+
+        // a faucet with a reserved slot of the wrong type
+        let faucet =
+            build_faucet(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, StorageSlot::empty_value());
+        assert!(matches!(
+            faucet.has_issued_assets(),
+            Err(AccountError::StorageSlotTypeMismatch { index: 0, .. })
+        ));
+
+        // regular accounts are not faucets
+        let account = build_account(vec![], Felt::new(1), vec![]);
+        assert_eq!(account.has_issued_assets(), Err(AccountError::NotAFaucet(account.id())));
+    }
+
+    #[test]
+    fn test_account_is_reserved_slot_default() {
+        // uninitialized faucets
+        let faucet = build_faucet(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, StorageSlot::empty_value());
+        assert!(faucet.is_reserved_slot_default().unwrap());
+        let faucet =
+            build_faucet(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, StorageSlot::empty_map());
+        assert!(faucet.is_reserved_slot_default().unwrap());
+
+        // seeded faucets
+        let faucet_data = [Felt::new(1), ZERO, ZERO, ZERO];
+        let faucet =
+            build_faucet(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, StorageSlot::Value(faucet_data));
+        assert!(!faucet.is_reserved_slot_default().unwrap());
+
+        let asset = NonFungibleAsset::mock(
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            &NON_FUNGIBLE_ASSET_DATA,
+        );
+        let issued_assets =
+            StorageMap::with_entries([(Word::from(asset).into(), asset.into())]).unwrap();
+        let faucet =
+            build_faucet(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, StorageSlot::Map(issued_assets));
+        assert!(!faucet.is_reserved_slot_default().unwrap());
+
+        // regular accounts do not have a reserved slot
+        let account = build_account(vec![], Felt::new(1), vec![]);
+        assert_eq!(account.is_reserved_slot_default(), Err(AccountError::NotAFaucet(account.id())));
+    }
+
+    #[test]
+    fn test_account_eq_ignoring_vault() {
+        let (asset_0, asset_1) = build_assets();
+        let slots =
+            vec![StorageSlot::Value([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)])];
+
+        let account = build_account(vec![asset_0], Felt::new(1), slots.clone());
+        let other_vault = build_account(vec![asset_1], Felt::new(1), slots.clone());
+        assert_ne!(account, other_vault);
+        assert!(account.eq_ignoring_vault(&other_vault));
+
+        let other_nonce = build_account(vec![asset_0], Felt::new(2), slots);
+        assert!(!account.eq_ignoring_vault(&other_nonce));
+
+        let other_storage = build_account(vec![asset_0], Felt::new(1), vec![]);
+        assert!(!account.eq_ignoring_vault(&other_storage));
+    }
+
+    #[test]
+    fn test_account_validate() {
+        let account = build_account(vec![], Felt::new(1), vec![]);
+        account.validate().unwrap();
+
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet = Account::from_parts(
+            faucet_id,
+            AssetVault::default(),
+            AccountStorage::new(vec![StorageSlot::empty_value()]).unwrap(),
+            AccountCode::mock(),
+            Felt::new(1),
+        );
+        faucet.validate().unwrap();
+
+        // a faucet without the reserved slot is rejected, also after a serialization round-trip
+        let faucet = Account::from_parts(
+            faucet_id,
+            AssetVault::default(),
+            AccountStorage::new(vec![]).unwrap(),
+            AccountCode::mock(),
+            Felt::new(1),
+        );
+        let bytes = faucet.to_bytes();
+        let faucet = Account::read_from_bytes(&bytes).unwrap();
+        assert_eq!(
+            faucet.validate(),
+            Err(AccountError::StorageIndexOutOfBounds { max: 0, actual: 0 })
+        );
+        assert!(matches!(
+            Account::read_from_bytes_validated(&bytes),
+            Err(DeserializationError::InvalidValue(_))
+        ));
+
+        // a well-formed account passes validated deserialization
+        let account = build_account(vec![], Felt::new(1), vec![]);
+        assert_eq!(Account::read_from_bytes_validated(&account.to_bytes()).unwrap(), account);
+    }
+
+    #[test]
+    fn test_account_validate_storage_layout() {
+        let component = AccountComponent::compile(
+            "export.foo push.1 drop end",
+            Assembler::default(),
+            vec![StorageSlot::empty_value()],
+        )
+        .unwrap()
+        .with_supports_all_types();
+
+        // the code of a regular account accesses slot 0, which does not exist in its storage
+        let code = AccountCode::from_components(
+            &[component.clone()],
+            AccountType::RegularAccountImmutableCode,
+        )
+        .unwrap();
+        let account = Account::from_parts(
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap(),
+            AssetVault::default(),
+            AccountStorage::new(vec![]).unwrap(),
+            code.clone(),
+            Felt::new(1),
+        );
+        assert_eq!(
+            account.validate(),
+            Err(AccountError::StorageOffsetOutOfBounds { max: 0, actual: 1 })
+        );
+
+        // the same code used by a faucet would access the reserved slot
+        let faucet = Account::from_parts(
+            AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap(),
+            AssetVault::default(),
+            AccountStorage::new(vec![StorageSlot::empty_value()]).unwrap(),
+            code,
+            Felt::new(1),
+        );
+        assert!(matches!(faucet.validate(), Err(AccountError::InvalidComponentStorageLayout(_))));
+
+        // code built for the faucet type places the component after the reserved slot
+        let code = AccountCode::from_components(&[component], AccountType::FungibleFaucet).unwrap();
+        let faucet = Account::from_parts(
+            AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap(),
+            AssetVault::default(),
+            AccountStorage::new(vec![StorageSlot::empty_value(), StorageSlot::empty_value()])
+                .unwrap(),
+            code,
+            Felt::new(1),
+        );
+        faucet.validate().unwrap();
+    }
+
+    #[test]
+    fn test_compute_commitments() {
+        let library = Assembler::default().assemble_library(["export.foo add end"]).unwrap();
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let component = AccountComponent::new(library, vec![StorageSlot::Value(word)])
+            .unwrap()
+            .with_supports_all_types();
+
+        for account_type in [AccountType::RegularAccountUpdatableCode, AccountType::FungibleFaucet]
+        {
+            let components = [component.clone()];
+            let (code, storage) =
+                Account::initialize_from_components(account_type, &components).unwrap();
+
+            let (code_commitment, storage_commitment) =
+                compute_commitments(&components, account_type).unwrap();
+            assert_eq!(code_commitment, code.commitment());
+            assert_eq!(storage_commitment, storage.commitment());
+        }
+    }
 }
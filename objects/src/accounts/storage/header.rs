@@ -3,7 +3,7 @@ use alloc::vec::Vec;
 use vm_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 use vm_processor::DeserializationError;
 
-use super::{AccountStorage, StorageSlotType, Word};
+use super::{AccountStorage, Digest, Felt, Hasher, StorageSlotType, Word};
 use crate::AccountError;
 
 // ACCOUNT STORAGE HEADER
@@ -32,6 +32,13 @@ impl AccountStorageHeader {
         Self { slots }
     }
 
+    /// Returns the storage header of the provided account storage.
+    ///
+    /// The commitment of the returned header is equal to the commitment of the account storage.
+    pub fn from_storage(storage: &AccountStorage) -> Self {
+        storage.get_header()
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -55,6 +62,19 @@ impl AccountStorageHeader {
             actual: index as u8,
         })
     }
+
+    /// Returns a commitment to the storage slots contained in this header.
+    ///
+    /// The commitment is computed in the same way as [AccountStorage::commitment()], and thus the
+    /// two are equal for a header built from the account storage.
+    pub fn commitment(&self) -> Digest {
+        let elements: Vec<Felt> = self
+            .slots
+            .iter()
+            .flat_map(|(slot_type, value)| value.iter().chain(slot_type.as_word().iter()).copied())
+            .collect();
+        Hasher::hash_elements(&elements)
+    }
 }
 
 impl From<AccountStorage> for AccountStorageHeader {
@@ -94,7 +114,7 @@ mod tests {
     };
 
     use super::AccountStorageHeader;
-    use crate::accounts::{AccountStorage, StorageSlotType};
+    use crate::accounts::{AccountStorage, StorageMap, StorageSlot, StorageSlotType};
 
     #[test]
     fn test_from_account_storage() {
@@ -119,6 +139,46 @@ mod tests {
         assert_eq!(expected_header, AccountStorageHeader::from(account_storage))
     }
 
+    #[test]
+    fn test_header_commitment_matches_storage_commitment() {
+        let map = StorageMap::with_entries([(
+            [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)].into(),
+            [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)],
+        )])
+        .unwrap();
+        let storage = AccountStorage::new(vec![
+            StorageSlot::Value([Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)]),
+            StorageSlot::Map(map),
+            StorageSlot::empty_value(),
+            StorageSlot::empty_map(),
+        ])
+        .unwrap();
+
+        let header = AccountStorageHeader::from_storage(&storage);
+        assert_eq!(header.num_slots(), 4);
+        assert_eq!(header.commitment(), storage.commitment());
+    }
+
+    #[test]
+    fn test_header_commitment_from_slot_commitments() {
+        let map = StorageMap::with_entries([(
+            [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)].into(),
+            [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)],
+        )])
+        .unwrap();
+        let storage = AccountStorage::new(vec![
+            StorageSlot::Map(map),
+            StorageSlot::Value([Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)]),
+        ])
+        .unwrap();
+
+        // a verifier only knowing the slot types and commitments recomputes the same commitment
+        let header = AccountStorageHeader::new(
+            storage.slots().iter().map(|slot| (slot.slot_type(), slot.value())).collect(),
+        );
+        assert_eq!(header.commitment(), storage.commitment());
+    }
+
     #[test]
     fn test_serde_account_storage_header() {
         // create new storage header
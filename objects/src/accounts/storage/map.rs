@@ -1,3 +1,5 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
 use miden_crypto::merkle::EmptySubtreeRoots;
 
 use super::{
@@ -47,6 +49,10 @@ impl StorageMap {
         StorageMap { map: Smt::new() }
     }
 
+    /// Returns a new [StorageMap] instantiated with the provided entries.
+    ///
+    /// Entries are inserted into the underlying tree one at a time, so the root is computed
+    /// incrementally without materializing all entries at once.
     pub fn with_entries(
         entries: impl IntoIterator<Item = (RpoDigest, Word)>,
     ) -> Result<Self, AccountError> {
@@ -71,6 +77,14 @@ impl StorageMap {
         self.map.root() // Delegate to Smt's root method
     }
 
+    /// Returns a commitment to this storage map, i.e., the root of the underlying Sparse Merkle
+    /// Tree.
+    ///
+    /// The root is updated incrementally on every insertion, so this does not recompute the tree.
+    pub fn commitment(&self) -> Digest {
+        self.map.root()
+    }
+
     pub fn get_leaf(&self, key: &RpoDigest) -> SmtLeaf {
         self.map.get_leaf(key) // Delegate to Smt's get_leaf method
     }
@@ -97,6 +111,29 @@ impl StorageMap {
         self.map.inner_nodes() // Delegate to Smt's inner_nodes method
     }
 
+    /// Returns the keys whose values differ between this map and the `other` map, sorted in
+    /// ascending order.
+    ///
+    /// This includes keys present in only one of the maps as well as keys present in both maps
+    /// with different values. Updating these keys in this map to the values from `other` results
+    /// in a map equal to `other`.
+    pub fn diff_keys(&self, other: &StorageMap) -> Vec<Digest> {
+        let mut keys: BTreeSet<Digest> = self
+            .entries()
+            .filter(|(key, value)| other.get_value(key) != *value)
+            .map(|(key, _)| *key)
+            .collect();
+
+        keys.extend(
+            other
+                .entries()
+                .filter(|(key, value)| self.get_value(key) != *value)
+                .map(|(key, _)| *key),
+        );
+
+        keys.into_iter().collect()
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
     pub fn insert(&mut self, key: RpoDigest, value: Word) -> Word {
@@ -142,7 +179,7 @@ impl Deserializable for StorageMap {
 
 #[cfg(test)]
 mod tests {
-    use miden_crypto::{hash::rpo::RpoDigest, Felt};
+    use miden_crypto::{hash::rpo::RpoDigest, merkle::Smt, Felt};
 
     use super::{Deserializable, Serializable, StorageMap, Word, EMPTY_STORAGE_MAP_ROOT};
 
@@ -175,4 +212,79 @@ mod tests {
         // If these values don't match, update the constants.
         assert_eq!(StorageMap::default().root(), EMPTY_STORAGE_MAP_ROOT);
     }
+
+    #[test]
+    fn test_commitment_after_mutations() {
+        let key =
+            |i: u64| RpoDigest::new([Felt::new(i), Felt::new(i + 1), Felt::new(0), Felt::new(0)]);
+        let value = |i: u64| [Felt::new(i), Felt::new(0), Felt::new(0), Felt::new(1)];
+
+        let mut storage_map = StorageMap::new();
+        for i in 0..10 {
+            storage_map.insert(key(i), value(i));
+        }
+        // overwrite some entries and remove others by setting them to the empty value
+        for i in 0..5 {
+            storage_map.insert(key(i), value(i + 100));
+        }
+        for i in 5..8 {
+            storage_map.insert(key(i), StorageMap::EMPTY_VALUE);
+        }
+
+        let expected = StorageMap::with_entries(
+            (0..5)
+                .map(|i| (key(i), value(i + 100)))
+                .chain((8..10).map(|i| (key(i), value(i)))),
+        )
+        .unwrap();
+        assert_eq!(storage_map.commitment(), expected.commitment());
+
+        // removing all entries results in the empty root
+        for i in 0..10 {
+            storage_map.insert(key(i), StorageMap::EMPTY_VALUE);
+        }
+        assert_eq!(storage_map.commitment(), EMPTY_STORAGE_MAP_ROOT);
+    }
+
+    #[test]
+    fn test_commitment_of_large_map() {
+        let entries = (0..1000_u64).map(|i| {
+            (
+                RpoDigest::new([Felt::new(i), Felt::new(i * 7), Felt::new(0), Felt::new(i % 13)]),
+                [Felt::new(i), Felt::new(1), Felt::new(2), Felt::new(3)],
+            )
+        });
+
+        let storage_map = StorageMap::with_entries(entries.clone()).unwrap();
+        let smt = Smt::with_entries(entries).unwrap();
+        assert_eq!(storage_map.commitment(), smt.root());
+    }
+
+    #[test]
+    fn test_diff_keys() {
+        let key = |i: u64| RpoDigest::new([Felt::new(i), Felt::new(0), Felt::new(0), Felt::new(0)]);
+        let value = |i: u64| [Felt::new(i), Felt::new(0), Felt::new(0), Felt::new(0)];
+
+        let map =
+            StorageMap::with_entries([(key(1), value(1)), (key(2), value(2)), (key(3), value(3))])
+                .unwrap();
+        assert!(map.diff_keys(&map.clone()).is_empty());
+
+        // key 1 is removed, key 2 is changed, key 3 is unchanged and key 4 is added
+        let other =
+            StorageMap::with_entries([(key(2), value(20)), (key(3), value(3)), (key(4), value(4))])
+                .unwrap();
+
+        let mut expected = vec![key(1), key(2), key(4)];
+        expected.sort();
+        assert_eq!(map.diff_keys(&other), expected);
+        assert_eq!(other.diff_keys(&map), expected);
+
+        // updating the differing keys yields the other map
+        let mut updated = map.clone();
+        for key in map.diff_keys(&other) {
+            updated.insert(key, other.get_value(&key));
+        }
+        assert_eq!(updated.commitment(), other.commitment());
+    }
 }
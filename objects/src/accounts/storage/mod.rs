@@ -36,6 +36,9 @@ impl AccountStorage {
     /// The maximum number of storage slots allowed in an account storage.
     pub const MAX_NUM_STORAGE_SLOTS: usize = 255;
 
+    /// The index of the storage slot reserved for faucet data in faucet accounts.
+    pub const FAUCET_DATA_SLOT: u8 = 0;
+
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
 
@@ -94,7 +97,7 @@ impl AccountStorage {
 
     /// Returns a commitment to this storage.
     pub fn commitment(&self) -> Digest {
-        build_slots_commitment(&self.slots)
+        compute_storage_commitment(&self.slots)
     }
 
     /// Converts storage slots of this account storage into a vector of field elements.
@@ -147,34 +150,41 @@ impl AccountStorage {
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
-    /// Applies the provided delta to this account storage.
+    /// Applies the provided delta to this account storage and returns the indices of the slots
+    /// touched by the delta in ascending order.
+    ///
+    /// The delta is validated against the current slot layout before any slot is modified, so on
+    /// error the storage is left unchanged.
     ///
     /// # Errors:
-    /// - If the updates violate storage constraints.
-    pub(super) fn apply_delta(&mut self, delta: &AccountStorageDelta) -> Result<(), AccountError> {
-        let len = self.slots.len() as u8;
+    /// - If the delta references a slot index which is out of bounds.
+    /// - If a value update (including a cleared item) targets a map slot, or a map update targets a
+    ///   value slot.
+    pub fn apply_delta(&mut self, delta: &AccountStorageDelta) -> Result<Vec<u8>, AccountError> {
+        let maps = delta.maps().keys().map(|&idx| (idx, StorageSlotType::Map));
+        let values = delta.values().keys().map(|&idx| (idx, StorageSlotType::Value));
+        for (idx, expected) in maps.chain(values) {
+            self.check_slot_type(idx, expected)?;
+        }
 
         // update storage maps
         for (&idx, map) in delta.maps().iter() {
-            let storage_slot = self
-                .slots
-                .get_mut(idx as usize)
-                .ok_or(AccountError::StorageIndexOutOfBounds { max: len, actual: idx })?;
-
-            let storage_map = match storage_slot {
-                StorageSlot::Map(map) => map,
-                _ => return Err(AccountError::StorageSlotNotMap(idx)),
-            };
-
-            storage_map.apply_delta(map);
+            if let StorageSlot::Map(storage_map) = &mut self.slots[idx as usize] {
+                storage_map.apply_delta(map);
+            }
         }
 
         // update storage values
         for (&idx, &value) in delta.values().iter() {
-            self.set_item(idx, value)?;
+            self.slots[idx as usize] = StorageSlot::Value(value);
         }
 
-        Ok(())
+        // a slot cannot be both a map and a value slot, so the indices are unique
+        let mut updated_slots: Vec<u8> =
+            delta.maps().keys().chain(delta.values().keys()).copied().collect();
+        updated_slots.sort_unstable();
+
+        Ok(updated_slots)
     }
 
     /// Updates the value of the storage slot at the specified index.
@@ -245,6 +255,28 @@ impl AccountStorage {
 
         Ok((old_root.into(), old_value))
     }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that the slot at the specified index exists and has the expected type.
+    pub(super) fn check_slot_type(
+        &self,
+        index: u8,
+        expected: StorageSlotType,
+    ) -> Result<(), AccountError> {
+        let slot = self.slots.get(index as usize).ok_or(AccountError::StorageIndexOutOfBounds {
+            max: self.slots.len() as u8,
+            actual: index,
+        })?;
+
+        let found = slot.slot_type();
+        if found != expected {
+            return Err(AccountError::StorageSlotTypeMismatch { index, expected, found });
+        }
+
+        Ok(())
+    }
 }
 
 // HELPER FUNCTIONS
@@ -255,8 +287,12 @@ fn slots_as_elements(slots: &[StorageSlot]) -> Vec<Felt> {
     slots.iter().flat_map(|slot| slot.as_elements()).collect()
 }
 
-/// Computes the commitment to the given slots
-fn build_slots_commitment(slots: &[StorageSlot]) -> Digest {
+/// Computes the commitment to the given storage slots.
+///
+/// The returned commitment is the same as the one returned by [AccountStorage::commitment()] for
+/// an account storage consisting of these slots. This allows verifying a storage commitment
+/// without instantiating an [AccountStorage].
+pub fn compute_storage_commitment(slots: &[StorageSlot]) -> Digest {
     let elements = slots_as_elements(slots);
     Hasher::hash_elements(&elements)
 }
@@ -297,10 +333,18 @@ impl Deserializable for AccountStorage {
 
 #[cfg(test)]
 mod tests {
+    use assembly::Assembler;
+
     use super::{
-        build_slots_commitment, AccountStorage, Deserializable, Serializable, StorageMap, Word,
+        compute_storage_commitment, AccountStorage, Deserializable, Serializable, StorageMap, Word,
+    };
+    use crate::{
+        accounts::{
+            AccountComponent, AccountStorageDelta, AccountType, StorageMapDelta, StorageSlot,
+            StorageSlotType,
+        },
+        AccountError, Digest, ONE, ZERO,
     };
-    use crate::accounts::StorageSlot;
 
     #[test]
     fn test_serde_account_storage() {
@@ -319,10 +363,160 @@ mod tests {
         assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
     }
 
+    #[test]
+    fn test_deserialize_account_storage_with_corrupt_slot_count() {
+        // the slot count is serialized as a u8 and thus can never exceed the maximum of 255 slots;
+        // a payload claiming more slots than it contains must fail with an error instead of
+        // panicking
+        let mut bytes = AccountStorage::new(vec![StorageSlot::empty_value()]).unwrap().to_bytes();
+        bytes[0] = u8::MAX;
+        assert!(AccountStorage::read_from_bytes(&bytes).is_err());
+    }
+
     #[test]
     fn test_account_storage_slots_commitment() {
         let storage = AccountStorage::mock();
-        let storage_slots_commitment = build_slots_commitment(storage.slots());
+        let storage_slots_commitment = compute_storage_commitment(storage.slots());
         assert_eq!(storage_slots_commitment, storage.commitment())
     }
+
+    #[test]
+    fn test_set_item() {
+        let mut storage = AccountStorage::new(vec![
+            StorageSlot::Value([ONE; 4]),
+            StorageSlot::Map(StorageMap::default()),
+        ])
+        .unwrap();
+
+        // updating a value slot returns the previous value
+        let new_value: Word = [ONE, ONE, ZERO, ZERO];
+        assert_eq!(storage.set_item(0, new_value), Ok([ONE; 4]));
+        assert_eq!(storage.slots()[0], StorageSlot::Value(new_value));
+
+        // updating a slot which is out of bounds is rejected
+        assert_eq!(
+            storage.set_item(2, new_value),
+            Err(AccountError::StorageIndexOutOfBounds { max: 2, actual: 2 })
+        );
+
+        // updating a map slot is rejected
+        assert_eq!(storage.set_item(1, new_value), Err(AccountError::StorageSlotNotValue(1)));
+        assert_eq!(storage.slots()[1], StorageSlot::Map(StorageMap::default()));
+    }
+
+    #[test]
+    fn test_compute_storage_commitment() {
+        let slots = vec![
+            StorageSlot::Value([ONE; 4]),
+            StorageSlot::Map(StorageMap::default()),
+            StorageSlot::empty_value(),
+        ];
+        let storage = AccountStorage::new(slots.clone()).unwrap();
+
+        assert_eq!(compute_storage_commitment(&slots), storage.commitment());
+        assert_ne!(compute_storage_commitment(&slots[..2]), storage.commitment());
+    }
+
+    #[test]
+    fn test_apply_delta_returns_updated_slots() {
+        let mut storage = AccountStorage::new(vec![
+            StorageSlot::Value([ONE; 4]),
+            StorageSlot::Map(StorageMap::default()),
+            StorageSlot::Value(Word::default()),
+            StorageSlot::Value(Word::default()),
+        ])
+        .unwrap();
+
+        let map_delta = StorageMapDelta::from_iters([], [([ONE; 4], [ONE; 4])]);
+        let delta = AccountStorageDelta::from_iters([0], [(3, [ONE; 4])], [(1, map_delta)]);
+        assert_eq!(storage.apply_delta(&delta).unwrap(), vec![0, 1, 3]);
+
+        assert_eq!(storage.apply_delta(&AccountStorageDelta::default()).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_apply_delta_value_update_to_map_slot() {
+        let mut storage = AccountStorage::new(vec![
+            StorageSlot::Value(Word::default()),
+            StorageSlot::Map(StorageMap::default()),
+        ])
+        .unwrap();
+        let original = storage.clone();
+
+        // clearing a map slot is rejected
+        let delta = AccountStorageDelta::from_iters([1], [], []);
+        assert_eq!(
+            storage.apply_delta(&delta),
+            Err(AccountError::StorageSlotTypeMismatch {
+                index: 1,
+                expected: StorageSlotType::Value,
+                found: StorageSlotType::Map,
+            })
+        );
+
+        // updating a map slot with a value is rejected
+        let delta = AccountStorageDelta::from_iters([], [(1, [ONE; 4])], []);
+        assert_eq!(
+            storage.apply_delta(&delta),
+            Err(AccountError::StorageSlotTypeMismatch {
+                index: 1,
+                expected: StorageSlotType::Value,
+                found: StorageSlotType::Map,
+            })
+        );
+        assert_eq!(storage, original);
+    }
+
+    #[test]
+    fn test_apply_delta_map_update_to_value_slot() {
+        let mut storage = AccountStorage::new(vec![
+            StorageSlot::Value(Word::default()),
+            StorageSlot::Map(StorageMap::default()),
+        ])
+        .unwrap();
+        let original = storage.clone();
+
+        let map_delta = StorageMapDelta::from_iters([], [([ONE; 4], [ONE; 4])]);
+        let delta = AccountStorageDelta::from_iters([], [], [(0, map_delta)]);
+        assert_eq!(
+            storage.apply_delta(&delta),
+            Err(AccountError::StorageSlotTypeMismatch {
+                index: 0,
+                expected: StorageSlotType::Map,
+                found: StorageSlotType::Value,
+            })
+        );
+        assert_eq!(storage, original);
+    }
+
+    #[test]
+    fn test_storage_from_slots() {
+        let storage_map = StorageMap::with_entries([(Digest::new([ONE; 4]), [ONE; 4])]).unwrap();
+        let slots = vec![
+            StorageSlot::Value([ONE; 4]),
+            StorageSlot::Map(storage_map),
+            StorageSlot::empty_value(),
+        ];
+
+        // building storage directly from slots matches building it from a component
+        let library = Assembler::default().assemble_library(["export.foo add end"]).unwrap();
+        let component =
+            AccountComponent::new(library, slots.clone()).unwrap().with_supports_all_types();
+        let expected =
+            AccountStorage::from_components(&[component], AccountType::RegularAccountUpdatableCode)
+                .unwrap();
+
+        let storage = AccountStorage::new(slots).unwrap();
+        assert_eq!(storage, expected);
+        assert_eq!(storage.commitment(), expected.commitment());
+
+        // the number of slots is limited
+        let slots = vec![StorageSlot::empty_value(); AccountStorage::MAX_NUM_STORAGE_SLOTS + 1];
+        assert_eq!(
+            AccountStorage::new(slots),
+            Err(AccountError::StorageTooManySlots(
+                AccountStorage::MAX_NUM_STORAGE_SLOTS as u64 + 1
+            ))
+        );
+    }
 }
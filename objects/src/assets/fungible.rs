@@ -47,6 +47,65 @@ impl FungibleAsset {
         asset.validate()
     }
 
+    /// Returns a fungible asset instantiated with the provided faucet ID and an amount parsed from
+    /// a human-readable decimal string, e.g., `"1.5"`.
+    ///
+    /// The parsed amount is scaled by `10^decimals` to obtain the amount in base units, where
+    /// `decimals` is the number of decimals of the faucet's token.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The string is not a non-negative decimal number, i.e., digits optionally followed by a `.`
+    ///   and more digits.
+    /// - The number has more fractional digits than `decimals`.
+    /// - The scaled amount does not fit into a `u64`.
+    /// - The faucet_id is not a valid fungible faucet ID.
+    /// - The scaled amount is greater than 2^63 - 1.
+    pub fn from_decimal_str(
+        faucet_id: AccountId,
+        amount: &str,
+        decimals: u8,
+    ) -> Result<Self, AssetError> {
+        let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+        let is_digits = |part: &str| part.bytes().all(|byte| byte.is_ascii_digit());
+        if whole.is_empty()
+            || !is_digits(whole)
+            || !is_digits(fraction)
+            || (amount.contains('.') && fraction.is_empty())
+        {
+            return Err(AssetError::InvalidDecimalAmount(format!(
+                "{amount} is not a valid decimal amount"
+            )));
+        }
+
+        if fraction.len() > decimals as usize {
+            return Err(AssetError::InvalidDecimalAmount(format!(
+                "{amount} has more than {decimals} fractional digits"
+            )));
+        }
+
+        // parses the provided digits and scales them by 10^exponent, returning None on overflow
+        let scale = |digits: &str, exponent: usize| -> Option<u64> {
+            let value = if digits.is_empty() {
+                0
+            } else {
+                digits.parse::<u64>().ok()?
+            };
+            10_u64.checked_pow(exponent as u32)?.checked_mul(value)
+        };
+
+        let base_units = scale(whole, decimals as usize)
+            .zip(scale(fraction, decimals as usize - fraction.len()))
+            .and_then(|(whole_units, fraction_units)| whole_units.checked_add(fraction_units))
+            .ok_or_else(|| {
+                AssetError::InvalidDecimalAmount(format!(
+                    "{amount} overflows the amount in base units"
+                ))
+            })?;
+
+        Self::new(faucet_id, base_units)
+    }
+
     /// Creates a new [FungibleAsset] without checking its validity.
     pub(crate) fn new_unchecked(value: Word) -> FungibleAsset {
         FungibleAsset {
@@ -247,4 +306,43 @@ mod tests {
         let err = FungibleAsset::read_from_bytes(&asset_bytes).unwrap_err();
         assert!(matches!(err, DeserializationError::InvalidValue(_)));
     }
+
+    #[test]
+    fn test_fungible_asset_from_decimal_str() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        // whole numbers
+        let asset = FungibleAsset::from_decimal_str(faucet_id, "15", 8).unwrap();
+        assert_eq!(asset.amount(), 1_500_000_000);
+        assert_eq!(FungibleAsset::from_decimal_str(faucet_id, "15", 0).unwrap().amount(), 15);
+
+        // fractional amounts
+        assert_eq!(
+            FungibleAsset::from_decimal_str(faucet_id, "1.5", 8).unwrap().amount(),
+            150_000_000
+        );
+        assert_eq!(FungibleAsset::from_decimal_str(faucet_id, "0.001", 3).unwrap().amount(), 1);
+
+        // over-precision is rejected
+        assert!(matches!(
+            FungibleAsset::from_decimal_str(faucet_id, "1.0001", 3),
+            Err(AssetError::InvalidDecimalAmount(_))
+        ));
+
+        // malformed and overflowing amounts are rejected
+        for amount in ["", ".5", "1.", "1.2.3", "-1", "+1", "1e5", "a"] {
+            assert!(matches!(
+                FungibleAsset::from_decimal_str(faucet_id, amount, 8),
+                Err(AssetError::InvalidDecimalAmount(_))
+            ));
+        }
+        assert!(matches!(
+            FungibleAsset::from_decimal_str(faucet_id, "1000000000000", 8),
+            Err(AssetError::InvalidDecimalAmount(_))
+        ));
+        assert!(matches!(
+            FungibleAsset::from_decimal_str(faucet_id, "100000000000", 8),
+            Err(AssetError::AmountTooBig(_))
+        ));
+    }
 }
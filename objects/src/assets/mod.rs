@@ -96,6 +96,22 @@ impl Asset {
         matches!(self, Self::Fungible(_))
     }
 
+    /// Returns a reference to the inner fungible asset, or None if the asset is non-fungible.
+    pub const fn as_fungible(&self) -> Option<&FungibleAsset> {
+        match self {
+            Self::Fungible(asset) => Some(asset),
+            Self::NonFungible(_) => None,
+        }
+    }
+
+    /// Returns a reference to the inner non-fungible asset, or None if the asset is fungible.
+    pub const fn as_non_fungible(&self) -> Option<&NonFungibleAsset> {
+        match self {
+            Self::Fungible(_) => None,
+            Self::NonFungible(asset) => Some(asset),
+        }
+    }
+
     /// Returns ID of the faucet which issued this asset.
     pub fn faucet_id(&self) -> AccountId {
         match self {
@@ -295,4 +311,22 @@ mod tests {
             assert_eq!(non_fungible_asset, Asset::new_unchecked(Word::from(non_fungible_asset)));
         }
     }
+
+    #[test]
+    fn test_asset_downcast() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let fungible = FungibleAsset::new(faucet_id, 10).unwrap();
+        let asset = Asset::from(fungible);
+        assert!(asset.is_fungible());
+        assert_eq!(asset.as_fungible(), Some(&fungible));
+        assert_eq!(asset.as_non_fungible(), None);
+
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let details = NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap();
+        let non_fungible = NonFungibleAsset::new(&details).unwrap();
+        let asset = Asset::from(non_fungible);
+        assert!(!asset.is_fungible());
+        assert_eq!(asset.as_fungible(), None);
+        assert_eq!(asset.as_non_fungible(), Some(&non_fungible));
+    }
 }
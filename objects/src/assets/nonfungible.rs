@@ -57,7 +57,10 @@ impl NonFungibleAsset {
     /// Returns an error if the provided faucet ID is not for a non-fungible asset faucet.
     pub fn new(details: &NonFungibleAssetDetails) -> Result<Self, AssetError> {
         let data_hash = Hasher::hash(details.asset_data());
-        Self::from_parts(details.faucet_id(), data_hash.into())
+        match details.metadata() {
+            Some(metadata) => Self::with_metadata(details.faucet_id(), data_hash.into(), metadata),
+            None => Self::from_parts(details.faucet_id(), data_hash.into()),
+        }
     }
 
     /// Return a non-fungible asset created from the specified faucet and using the provided
@@ -99,6 +102,29 @@ impl NonFungibleAsset {
         Ok(asset)
     }
 
+    /// Returns a non-fungible asset created from the specified faucet and data hash, committing
+    /// to the provided metadata element (e.g., a category of the asset).
+    ///
+    /// The metadata is merged with the data hash as `hash(data_hash || [metadata, 0, 0, 0])`, and
+    /// the result is used as the data hash of the asset. Thus, assets created with the same data
+    /// hash but different metadata are distinct, and are also distinct from the asset created via
+    /// [NonFungibleAsset::from_parts()] for the same data hash.
+    ///
+    /// The metadata cannot be recovered from the asset itself; it is available via
+    /// [NonFungibleAssetDetails::metadata()].
+    ///
+    /// # Errors
+    /// Returns an error if the provided faucet ID is not for a non-fungible asset faucet.
+    pub fn with_metadata(
+        faucet_id: AccountId,
+        data_hash: Word,
+        metadata: Felt,
+    ) -> Result<Self, AssetError> {
+        let metadata: Word = [metadata, Felt::ZERO, Felt::ZERO, Felt::ZERO];
+        let data_hash = Hasher::merge(&[data_hash.into(), metadata.into()]);
+        Self::from_parts(faucet_id, data_hash.into())
+    }
+
     /// Creates a new [NonFungibleAsset] without checking its validity.
     ///
     /// # Safety
@@ -223,6 +249,7 @@ impl NonFungibleAsset {
 pub struct NonFungibleAssetDetails {
     faucet_id: AccountId,
     asset_data: Vec<u8>,
+    metadata: Option<Felt>,
 }
 
 impl NonFungibleAssetDetails {
@@ -235,7 +262,24 @@ impl NonFungibleAssetDetails {
             return Err(AssetError::NotANonFungibleFaucetId(faucet_id));
         }
 
-        Ok(Self { faucet_id, asset_data })
+        Ok(Self { faucet_id, asset_data, metadata: None })
+    }
+
+    /// Returns asset details instantiated from the specified faucet ID, asset data and metadata
+    /// element.
+    ///
+    /// The asset created from these details commits to the metadata as described in
+    /// [NonFungibleAsset::with_metadata()].
+    ///
+    /// # Errors
+    /// Returns an error if the provided faucet ID is not for a non-fungible asset faucet.
+    pub fn with_metadata(
+        faucet_id: AccountId,
+        asset_data: Vec<u8>,
+        metadata: Felt,
+    ) -> Result<Self, AssetError> {
+        let details = Self::new(faucet_id, asset_data)?;
+        Ok(Self { metadata: Some(metadata), ..details })
     }
 
     /// Returns ID of the faucet which issued this asset.
@@ -247,6 +291,11 @@ impl NonFungibleAssetDetails {
     pub fn asset_data(&self) -> &[u8] {
         &self.asset_data
     }
+
+    /// Returns the metadata element of this asset, or `None` if the asset carries no metadata.
+    pub fn metadata(&self) -> Option<Felt> {
+        self.metadata
+    }
 }
 
 // TESTS
@@ -285,4 +334,68 @@ mod tests {
         let err = NonFungibleAsset::read_from_bytes(&asset_bytes).unwrap_err();
         assert!(matches!(err, DeserializationError::InvalidValue(_)));
     }
+
+    #[test]
+    fn test_non_fungible_asset_with_metadata() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let data_hash: Word = Hasher::hash(&[1, 2, 3]).into();
+
+        let asset_0 = NonFungibleAsset::with_metadata(faucet_id, data_hash, Felt::new(7)).unwrap();
+        let asset_1 = NonFungibleAsset::with_metadata(faucet_id, data_hash, Felt::new(8)).unwrap();
+        assert_eq!(asset_0.faucet_id(), faucet_id);
+        assert_ne!(asset_0, asset_1);
+
+        let word: Word = asset_0.into();
+        assert_eq!(NonFungibleAsset::try_from(word).unwrap(), asset_0);
+        assert_eq!(NonFungibleAsset::read_from_bytes(&asset_0.to_bytes()).unwrap(), asset_0);
+
+        let fungible_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN).unwrap();
+        assert!(
+            NonFungibleAsset::with_metadata(fungible_faucet_id, data_hash, Felt::new(7)).is_err()
+        );
+    }
+
+    #[test]
+    fn test_non_fungible_asset_metadata_is_committed_to() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let data_hash: Word = Hasher::hash(&[1, 2, 3]).into();
+
+        // data hashes which differ only in the element at position 2
+        let mut other_data_hash = data_hash;
+        other_data_hash[2] = Felt::new(7);
+
+        let plain = NonFungibleAsset::from_parts(faucet_id, data_hash).unwrap();
+        let with_metadata =
+            NonFungibleAsset::with_metadata(faucet_id, data_hash, Felt::new(7)).unwrap();
+        let other_with_metadata =
+            NonFungibleAsset::with_metadata(faucet_id, other_data_hash, Felt::new(7)).unwrap();
+
+        assert_ne!(plain.vault_key(), with_metadata.vault_key());
+        assert_ne!(with_metadata.vault_key(), other_with_metadata.vault_key());
+        assert_ne!(
+            NonFungibleAsset::from_parts(faucet_id, other_data_hash).unwrap(),
+            with_metadata
+        );
+    }
+
+    #[test]
+    fn test_non_fungible_asset_details_metadata() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let plain = NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap();
+        let details =
+            NonFungibleAssetDetails::with_metadata(faucet_id, vec![1, 2, 3], Felt::new(7)).unwrap();
+        assert_eq!(plain.metadata(), None);
+        assert_eq!(details.metadata(), Some(Felt::new(7)));
+
+        let data_hash: Word = Hasher::hash(&[1, 2, 3]).into();
+        assert_eq!(
+            NonFungibleAsset::new(&details).unwrap(),
+            NonFungibleAsset::with_metadata(faucet_id, data_hash, Felt::new(7)).unwrap()
+        );
+        assert_ne!(
+            NonFungibleAsset::new(&plain).unwrap(),
+            NonFungibleAsset::new(&details).unwrap()
+        );
+    }
 }
@@ -1,4 +1,8 @@
-use alloc::{string::ToString, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    vec::Vec,
+};
 
 use super::{
     AccountId, AccountType, Asset, ByteReader, ByteWriter, Deserializable, DeserializationError,
@@ -6,8 +10,8 @@ use super::{
 };
 use crate::{
     accounts::{AccountVaultDelta, NonFungibleDeltaAction},
-    crypto::merkle::Smt,
-    AssetVaultError, Digest,
+    crypto::merkle::{Smt, SmtProof},
+    AssetVaultError, Digest, Word,
 };
 // ASSET VAULT
 // ================================================================================================
@@ -90,10 +94,127 @@ impl AssetVault {
     }
 
     /// Returns a bool indicating whether the vault is empty.
+    ///
+    /// This compares the root of the underlying asset tree against the empty root and therefore
+    /// does not iterate over the assets in the vault.
     pub fn is_empty(&self) -> bool {
         self.asset_tree.is_empty()
     }
 
+    /// Returns the assets stored in this vault grouped by the ID of their issuing faucet.
+    ///
+    /// Since fungible assets issued by the same faucet are stored as a single asset, each fungible
+    /// faucet maps to exactly one asset, while a non-fungible faucet maps to all of its assets
+    /// stored in the vault.
+    pub fn group_by_faucet(&self) -> BTreeMap<AccountId, Vec<Asset>> {
+        let mut groups: BTreeMap<AccountId, Vec<Asset>> = BTreeMap::new();
+        for asset in self.assets() {
+            groups.entry(asset.faucet_id()).or_default().push(asset);
+        }
+
+        groups
+    }
+
+    /// Returns the IDs of all faucets which issued at least one of the assets stored in this vault.
+    pub fn faucet_ids(&self) -> BTreeSet<AccountId> {
+        self.assets().map(|asset| asset.faucet_id()).collect()
+    }
+
+    /// Returns the delta which transforms this vault into the `other` vault.
+    ///
+    /// Fungible assets are compared by balance per faucet, and thus the delta contains the net
+    /// balance change for every faucet whose balance differs between the two vaults. Non-fungible
+    /// assets present only in `other` are added, and those present only in this vault are removed.
+    ///
+    /// Applying the returned delta to a copy of this vault results in a vault equal to `other`.
+    pub fn diff(&self, other: &AssetVault) -> AccountVaultDelta {
+        let mut delta = AccountVaultDelta::default();
+
+        // removed assets and decreased balances
+        for asset in self.assets() {
+            match asset {
+                Asset::Fungible(fungible) => {
+                    let other_balance = other
+                        .get_balance(fungible.faucet_id())
+                        .expect("asset is issued by a fungible faucet");
+                    if fungible.amount() > other_balance {
+                        let removed = FungibleAsset::new(
+                            fungible.faucet_id(),
+                            fungible.amount() - other_balance,
+                        )
+                        .expect("amount is smaller than the current balance");
+                        delta.remove_asset(removed.into()).expect("fungible delta cannot overflow");
+                    }
+                },
+                Asset::NonFungible(_) => {
+                    if !other.has_non_fungible_asset(asset).expect("asset is non-fungible") {
+                        delta.remove_asset(asset).expect("non-fungible asset is removed once");
+                    }
+                },
+            }
+        }
+
+        // added assets and increased balances
+        for asset in other.assets() {
+            match asset {
+                Asset::Fungible(fungible) => {
+                    let balance = self
+                        .get_balance(fungible.faucet_id())
+                        .expect("asset is issued by a fungible faucet");
+                    if fungible.amount() > balance {
+                        let added =
+                            FungibleAsset::new(fungible.faucet_id(), fungible.amount() - balance)
+                                .expect("amount is smaller than the other balance");
+                        delta.add_asset(added.into()).expect("fungible delta cannot overflow");
+                    }
+                },
+                Asset::NonFungible(_) => {
+                    if !self.has_non_fungible_asset(asset).expect("asset is non-fungible") {
+                        delta.add_asset(asset).expect("non-fungible asset is added once");
+                    }
+                },
+            }
+        }
+
+        delta
+    }
+
+    /// Returns the number of bytes this vault occupies when serialized.
+    ///
+    /// The size is derived from the number of fungible and non-fungible assets stored in the vault,
+    /// and thus the vault does not need to be serialized to compute it.
+    pub fn serialized_size(&self) -> usize {
+        let (num_fungible, num_non_fungible) =
+            self.assets()
+                .fold((0usize, 0usize), |(fungible, non_fungible), asset| match asset {
+                    Asset::Fungible(_) => (fungible + 1, non_fungible),
+                    Asset::NonFungible(_) => (fungible, non_fungible + 1),
+                });
+
+        (num_fungible + num_non_fungible).get_size_hint()
+            + num_fungible * FungibleAsset::SERIALIZED_SIZE
+            + num_non_fungible * NonFungibleAsset::SERIALIZED_SIZE
+    }
+
+    /// Returns the value stored in this vault for the specified asset together with a proof of its
+    /// inclusion against the vault commitment, or None if the vault does not contain the asset.
+    ///
+    /// For fungible assets, the returned value is the asset holding the full balance of the
+    /// issuing faucet regardless of the amount of the provided asset, and thus the proof attests
+    /// to this balance. For non-fungible assets, the proof attests to the presence of the asset.
+    ///
+    /// The proof can be verified via [SmtProof::verify_membership()] using the vault key of the
+    /// asset, the returned value and the vault commitment.
+    pub fn open(&self, asset: &Asset) -> Option<(Word, SmtProof)> {
+        let key = asset.vault_key().into();
+        let value = self.asset_tree.get_value(&key);
+        if value == Smt::EMPTY_VALUE {
+            return None;
+        }
+
+        Some((value, self.asset_tree.open(&key)))
+    }
+
     // PUBLIC MODIFIERS
     // --------------------------------------------------------------------------------------------
 
@@ -126,6 +247,27 @@ impl AssetVault {
         Ok(())
     }
 
+    /// Merges all assets of the `other` vault into this vault.
+    ///
+    /// Amounts of fungible assets issued by the same faucet are added together, while
+    /// non-fungible assets are combined. The resulting vault has the same commitment as a vault
+    /// constructed from the assets of both vaults. If an error is returned, this vault is left
+    /// unchanged.
+    ///
+    /// # Errors
+    /// Returns an error:
+    /// - If the total amount of a fungible asset is greater than or equal to 2^63.
+    /// - If both vaults contain the same non-fungible asset.
+    pub fn merge(&mut self, other: &AssetVault) -> Result<(), AssetVaultError> {
+        let mut merged = self.clone();
+        for asset in other.assets() {
+            merged.add_asset(asset)?;
+        }
+        *self = merged;
+
+        Ok(())
+    }
+
     // ADD ASSET
     // --------------------------------------------------------------------------------------------
     /// Add the specified asset to the vault.
@@ -270,17 +412,7 @@ impl Serializable for AssetVault {
     }
 
     fn get_size_hint(&self) -> usize {
-        let mut size = 0;
-        let mut count: usize = 0;
-
-        for asset in self.assets() {
-            size += asset.get_size_hint();
-            count += 1;
-        }
-
-        size += count.get_size_hint();
-
-        size
+        self.serialized_size()
     }
 }
 
@@ -291,3 +423,226 @@ impl Deserializable for AssetVault {
         Self::new(&assets).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeSet, vec::Vec};
+
+    use super::{AccountId, Asset, AssetVault, FungibleAsset, NonFungibleAsset, Serializable};
+    use crate::{
+        accounts::account_id::testing::{
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        },
+        assets::NonFungibleAssetDetails,
+        AssetVaultError, Word,
+    };
+
+    /// Returns a non-fungible asset issued by the test non-fungible faucet for the provided data.
+    fn non_fungible_asset(data: u8) -> Asset {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        NonFungibleAsset::new(&NonFungibleAssetDetails::new(faucet_id, vec![data]).unwrap())
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn test_group_by_faucet() {
+        let faucet_0 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+        let non_fungible_faucet =
+            AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let fungible_0: Asset = FungibleAsset::new(faucet_0, 100).unwrap().into();
+        let fungible_1: Asset = FungibleAsset::new(faucet_1, 200).unwrap().into();
+        let non_fungible_0 = non_fungible_asset(1);
+        let non_fungible_1 = non_fungible_asset(2);
+
+        let vault =
+            AssetVault::new(&[fungible_0, non_fungible_0, fungible_1, non_fungible_1]).unwrap();
+        let groups = vault.group_by_faucet();
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&faucet_0], [fungible_0]);
+        assert_eq!(groups[&faucet_1], [fungible_1]);
+
+        let non_fungibles = &groups[&non_fungible_faucet];
+        assert_eq!(non_fungibles.len(), 2);
+        assert!(non_fungibles.contains(&non_fungible_0));
+        assert!(non_fungibles.contains(&non_fungible_1));
+
+        assert!(AssetVault::default().group_by_faucet().is_empty());
+    }
+
+    #[test]
+    fn test_faucet_ids() {
+        let faucet_0 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+        let non_fungible_faucet =
+            AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let vault = AssetVault::new(&[
+            FungibleAsset::new(faucet_0, 10).unwrap().into(),
+            FungibleAsset::new(faucet_1, 20).unwrap().into(),
+            non_fungible_asset(1),
+            non_fungible_asset(2),
+        ])
+        .unwrap();
+
+        assert_eq!(vault.faucet_ids(), BTreeSet::from([faucet_0, faucet_1, non_fungible_faucet]));
+        assert!(AssetVault::default().faucet_ids().is_empty());
+    }
+
+    #[test]
+    fn test_merge() {
+        let faucet_0 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+        let non_fungible_0 = non_fungible_asset(1);
+        let non_fungible_1 = non_fungible_asset(2);
+
+        let mut vault =
+            AssetVault::new(&[FungibleAsset::new(faucet_0, 100).unwrap().into(), non_fungible_0])
+                .unwrap();
+        let other = AssetVault::new(&[
+            FungibleAsset::new(faucet_0, 50).unwrap().into(),
+            FungibleAsset::new(faucet_1, 20).unwrap().into(),
+            non_fungible_1,
+        ])
+        .unwrap();
+        vault.merge(&other).unwrap();
+
+        let expected = AssetVault::new(&[
+            FungibleAsset::new(faucet_0, 150).unwrap().into(),
+            FungibleAsset::new(faucet_1, 20).unwrap().into(),
+            non_fungible_0,
+            non_fungible_1,
+        ])
+        .unwrap();
+        assert_eq!(vault.commitment(), expected.commitment());
+
+        // merging a vault which contains the same non-fungible asset fails
+        let duplicate = AssetVault::new(&[non_fungible_0]).unwrap();
+        assert!(matches!(
+            vault.merge(&duplicate),
+            Err(AssetVaultError::DuplicateNonFungibleAsset(_))
+        ));
+        assert_eq!(vault, expected);
+
+        // merging a vault which would overflow a fungible asset amount fails
+        let overflow = AssetVault::new(&[
+            FungibleAsset::new(faucet_1, 1).unwrap().into(),
+            FungibleAsset::new(faucet_0, FungibleAsset::MAX_AMOUNT).unwrap().into(),
+        ])
+        .unwrap();
+        assert!(matches!(
+            vault.merge(&overflow),
+            Err(AssetVaultError::AddFungibleAssetBalanceError(_))
+        ));
+        assert_eq!(vault, expected);
+    }
+
+    #[test]
+    fn test_open() {
+        let faucet = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let missing_faucet = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+
+        let fungible: Asset = FungibleAsset::new(faucet, 100).unwrap().into();
+        let non_fungible = non_fungible_asset(1);
+        let vault = AssetVault::new(&[fungible, non_fungible]).unwrap();
+
+        // the proof for a fungible asset attests to the full balance of the faucet
+        let (value, proof) = vault.open(&FungibleAsset::new(faucet, 1).unwrap().into()).unwrap();
+        assert_eq!(value, Word::from(fungible));
+        assert!(proof.verify_membership(&fungible.vault_key().into(), &value, &vault.commitment()));
+
+        let (value, proof) = vault.open(&non_fungible).unwrap();
+        assert_eq!(value, Word::from(non_fungible));
+        assert!(proof.verify_membership(
+            &non_fungible.vault_key().into(),
+            &value,
+            &vault.commitment()
+        ));
+
+        assert!(vault.open(&FungibleAsset::new(missing_faucet, 1).unwrap().into()).is_none());
+    }
+
+    #[test]
+    fn test_is_empty() {
+        assert!(AssetVault::default().is_empty());
+
+        let faucet = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset: Asset = FungibleAsset::new(faucet, 100).unwrap().into();
+        let mut vault = AssetVault::new(&[asset]).unwrap();
+        assert!(!vault.is_empty());
+
+        // removing the last asset makes the vault empty again
+        vault.remove_asset(asset).unwrap();
+        assert!(vault.is_empty());
+    }
+
+    #[test]
+    fn test_serialized_size() {
+        let faucet_0 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+
+        let fungible_0: Asset = FungibleAsset::new(faucet_0, 100).unwrap().into();
+        let fungible_1: Asset = FungibleAsset::new(faucet_1, 50).unwrap().into();
+        let non_fungible_0 = non_fungible_asset(1);
+        let non_fungible_1 = non_fungible_asset(2);
+
+        let vaults = [
+            AssetVault::default(),
+            AssetVault::new(&[fungible_0]).unwrap(),
+            AssetVault::new(&[non_fungible_0]).unwrap(),
+            AssetVault::new(&[fungible_0, fungible_1, non_fungible_0, non_fungible_1]).unwrap(),
+        ];
+
+        for vault in vaults {
+            assert_eq!(vault.serialized_size(), vault.to_bytes().len());
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let faucet_0 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let faucet_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+        let faucet_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+        let fungible =
+            |faucet, amount| -> Asset { FungibleAsset::new(faucet, amount).unwrap().into() };
+
+        let vault = AssetVault::new(&[
+            fungible(faucet_0, 100),
+            fungible(faucet_1, 50),
+            non_fungible_asset(1),
+            non_fungible_asset(2),
+        ])
+        .unwrap();
+        let other = AssetVault::new(&[
+            fungible(faucet_0, 30),
+            fungible(faucet_1, 50),
+            fungible(faucet_2, 10),
+            non_fungible_asset(2),
+            non_fungible_asset(3),
+        ])
+        .unwrap();
+
+        let delta = vault.diff(&other);
+        assert_eq!(
+            delta.added_assets().collect::<Vec<_>>(),
+            vec![fungible(faucet_2, 10), non_fungible_asset(3)]
+        );
+        assert_eq!(
+            delta.removed_assets().collect::<Vec<_>>(),
+            vec![fungible(faucet_0, 70), non_fungible_asset(1)]
+        );
+
+        let mut updated = vault.clone();
+        updated.apply_delta(&delta).unwrap();
+        assert_eq!(updated, other);
+
+        assert!(vault.diff(&vault).is_empty());
+    }
+}
@@ -95,6 +95,27 @@ impl BlockHeader {
         }
     }
 
+    /// Creates the header of the genesis block, i.e., the block with number 0.
+    ///
+    /// The genesis block has no previous block, and thus its previous block hash and chain root
+    /// are set to empty digests. All other fields not provided here (version, transaction hash,
+    /// kernel root, proof hash and timestamp) are set to zero.
+    pub fn genesis(account_root: Digest, nullifier_root: Digest, note_root: Digest) -> Self {
+        Self::new(
+            0,
+            Digest::default(),
+            0,
+            Digest::default(),
+            account_root,
+            nullifier_root,
+            note_root,
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            0,
+        )
+    }
+
     // ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -255,7 +276,7 @@ impl Deserializable for BlockHeader {
 
 #[cfg(test)]
 mod tests {
-    use vm_core::Word;
+    use vm_core::{Word, EMPTY_WORD};
     use winter_rand_utils::rand_array;
 
     use super::*;
@@ -277,4 +298,20 @@ mod tests {
 
         assert_eq!(deserialized, header);
     }
+
+    #[test]
+    fn test_genesis() {
+        let account_root: Word = rand_array();
+        let nullifier_root: Word = rand_array();
+        let note_root: Word = rand_array();
+        let header =
+            BlockHeader::genesis(account_root.into(), nullifier_root.into(), note_root.into());
+
+        assert_eq!(header.block_num(), 0);
+        assert_eq!(header.prev_hash(), Digest::from(EMPTY_WORD));
+        assert_eq!(header.account_root(), account_root.into());
+        assert_eq!(header.nullifier_root(), nullifier_root.into());
+        assert_eq!(header.note_root(), note_root.into());
+        assert_eq!(header.hash(), Hasher::merge(&[header.sub_hash(), header.note_root()]));
+    }
 }
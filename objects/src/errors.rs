@@ -4,11 +4,11 @@ use core::fmt;
 use vm_processor::DeserializationError;
 
 use super::{
-    accounts::{AccountId, StorageSlotType},
+    accounts::{AccountId, AccountStorageMode, StorageSlotType},
     assets::{Asset, FungibleAsset, NonFungibleAsset},
     crypto::merkle::MerkleError,
     notes::NoteId,
-    Digest, Word, MAX_ACCOUNTS_PER_BLOCK, MAX_BATCHES_PER_BLOCK, MAX_INPUT_NOTES_PER_BLOCK,
+    Digest, Felt, Word, MAX_ACCOUNTS_PER_BLOCK, MAX_BATCHES_PER_BLOCK, MAX_INPUT_NOTES_PER_BLOCK,
     MAX_OUTPUT_NOTES_PER_BATCH, MAX_OUTPUT_NOTES_PER_BLOCK,
 };
 use crate::{
@@ -35,7 +35,12 @@ pub enum AccountError {
     AccountCodeProcedureInvalidPadding,
     AccountIdInvalidFieldElement(String),
     AccountIdTooFewOnes(u32, u32),
+    AccountTypeMismatch {
+        expected: AccountType,
+        found: AccountType,
+    },
     AssetVaultUpdateError(AssetVaultError),
+    Base32ParseError(String),
     BuildError(String, Option<Box<AccountError>>),
     DuplicateStorageItems(MerkleError),
     FungibleFaucetIdInvalidFirstBit,
@@ -43,17 +48,28 @@ pub enum AccountError {
     HeaderDataIncorrectLength(usize, usize),
     HexParseError(String),
     InvalidAccountStorageMode,
+    InvalidComponentStorageLayout(String),
     MapsUpdateToNonMapsSlot(u8, StorageSlotType),
     NonceNotMonotonicallyIncreasing {
         current: u64,
         new: u64,
     },
+    NotAFaucet(AccountId),
     SeedDigestTooFewTrailingZeros {
         expected: u32,
         actual: u32,
     },
+    StorageModeMismatch {
+        expected: AccountStorageMode,
+        found: AccountStorageMode,
+    },
     StorageSlotNotMap(u8),
     StorageSlotNotValue(u8),
+    StorageSlotTypeMismatch {
+        index: u8,
+        expected: StorageSlotType,
+        found: StorageSlotType,
+    },
     StorageIndexOutOfBounds {
         max: u8,
         actual: u8,
@@ -64,6 +80,7 @@ pub enum AccountError {
         actual: u16,
     },
     PureProcedureWithStorageOffset,
+    UnresolvedComponentDependency(Digest),
     UnsupportedComponentForAccountType {
         account_type: AccountType,
         component_index: usize,
@@ -125,6 +142,7 @@ pub enum AssetError {
     FungibleAssetInvalidWord(Word),
     InconsistentFaucetIds(AccountId, AccountId),
     InvalidAccountId(String),
+    InvalidDecimalAmount(String),
     InvalidFieldElement(String),
     NonFungibleAssetInvalidTag(u32),
     NotAFungibleFaucetId(AccountId, AccountType),
@@ -173,19 +191,26 @@ impl std::error::Error for AssetVaultError {}
 pub enum NoteError {
     DuplicateFungibleAsset(AccountId),
     DuplicateNonFungibleAsset(NonFungibleAsset),
+    DuplicateSerialNumber(Word),
     InconsistentNoteTag(NoteType, u64),
     InvalidAssetData(AssetError),
+    InvalidNoteInputAccountId(AccountError),
+    InvalidNoteInputU32(Felt),
     InvalidNoteSender(AccountError),
+    InvalidNoteTagPayload(u32),
     InvalidNoteTagUseCase(u16),
     InvalidNoteExecutionHintTag(u8),
     InvalidNoteExecutionHintPayload(u8, u32),
     InvalidNoteType(NoteType),
     InvalidNoteTypeValue(u64),
     InvalidLocationIndex(String),
+    InvalidP2idrNote(String),
     InvalidStubDataLen(usize),
+    InvalidSwapNote(String),
     NetworkExecutionRequiresOnChainAccount,
     NetworkExecutionRequiresPublicNote(NoteType),
     NoteDeserializationError(DeserializationError),
+    NoteInputIndexOutOfBounds { offset: usize, num_inputs: usize },
     NoteScriptAssemblyError(String), // TODO: use Report
     NoteScriptDeserializationError(DeserializationError),
     PublicUseCaseRequiresPublicNote(NoteType),
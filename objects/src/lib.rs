@@ -53,6 +53,59 @@ pub mod utils {
         };
     }
 
+    /// Applies `f` to each of the provided items and returns the results in the order of the
+    /// items.
+    ///
+    /// With the `concurrent` feature enabled, the items are split into one chunk per available
+    /// thread and the chunks are processed in parallel.
+    #[cfg(not(feature = "concurrent"))]
+    pub(crate) fn par_map_chunks<T, R, F>(items: &[T], f: F) -> alloc::vec::Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync,
+    {
+        items.iter().map(f).collect()
+    }
+
+    /// Applies `f` to each of the provided items and returns the results in the order of the
+    /// items.
+    ///
+    /// With the `concurrent` feature enabled, the items are split into one chunk per available
+    /// thread and the chunks are processed in parallel. If there are fewer items than available
+    /// threads, the items are processed sequentially.
+    #[cfg(feature = "concurrent")]
+    pub(crate) fn par_map_chunks<T, R, F>(items: &[T], f: F) -> alloc::vec::Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync,
+    {
+        use alloc::vec::Vec;
+        use std::thread;
+
+        let thread_count = thread::available_parallelism().map_or(1, |v| v.get());
+        // spawning threads for tiny inputs costs more than mapping the items directly
+        if items.len() < thread_count {
+            return items.iter().map(f).collect();
+        }
+        let chunk_size = items.len().div_ceil(thread_count);
+        let f = &f;
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = items
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || chunk.iter().map(f).collect::<Vec<_>>()))
+                .collect();
+
+            // joining the handles in order preserves the order of the input items
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("worker thread panicked"))
+                .collect()
+        })
+    }
+
     /// Construct a new `Digest` from four `u64` values.
     #[macro_export]
     macro_rules! digest {
@@ -67,3 +120,25 @@ pub mod vm {
     pub use vm_core::{Program, ProgramInfo};
     pub use vm_processor::{AdviceInputs, AdviceMap, RowIndex, StackInputs, StackOutputs};
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::utils::par_map_chunks;
+
+    #[test]
+    fn test_par_map_chunks() {
+        // fewer items than available threads
+        assert_eq!(par_map_chunks(&[] as &[u32], |x| x * 2), Vec::<u32>::new());
+        assert_eq!(par_map_chunks(&[21u32], |x| x * 2), vec![42]);
+
+        // more items than available threads; the order of the items is preserved
+        let items: Vec<u32> = (0..1000).collect();
+        let expected: Vec<u32> = items.iter().map(|x| x * 2).collect();
+        assert_eq!(par_map_chunks(&items, |x| x * 2), expected);
+    }
+}
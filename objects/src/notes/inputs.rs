@@ -1,8 +1,8 @@
 use alloc::vec::Vec;
 
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher, NoteError,
-    Serializable, WORD_SIZE, ZERO,
+    AccountId, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher,
+    NoteError, Serializable, WORD_SIZE, ZERO,
 };
 use crate::MAX_INPUTS_PER_NOTE;
 
@@ -64,6 +64,27 @@ impl NoteInputs {
         &self.values
     }
 
+    /// Returns the input value at the specified offset interpreted as an [AccountId].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The offset is out of bounds.
+    /// - The value at the offset is not a valid account ID.
+    pub fn as_account_id(&self, offset: usize) -> Result<AccountId, NoteError> {
+        AccountId::try_from(self.get(offset)?).map_err(NoteError::InvalidNoteInputAccountId)
+    }
+
+    /// Returns the input value at the specified offset interpreted as a `u32`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The offset is out of bounds.
+    /// - The value at the offset does not fit into a `u32`.
+    pub fn as_u32(&self, offset: usize) -> Result<u32, NoteError> {
+        let value = self.get(offset)?;
+        u32::try_from(value.as_int()).map_err(|_| NoteError::InvalidNoteInputU32(value))
+    }
+
     /// Returns the note's input formatted to be used with the advice map.
     ///
     /// The format is `input_len || INPUTS || PADDING`, where:
@@ -77,6 +98,17 @@ impl NoteInputs {
         padded.insert(0, self.num_values().into());
         padded
     }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the input value at the specified offset.
+    fn get(&self, offset: usize) -> Result<Felt, NoteError> {
+        self.values
+            .get(offset)
+            .copied()
+            .ok_or(NoteError::NoteInputIndexOutOfBounds { offset, num_inputs: self.values.len() })
+    }
 }
 
 impl Default for NoteInputs {
@@ -162,7 +194,11 @@ impl Deserializable for NoteInputs {
 mod tests {
     use miden_crypto::utils::Deserializable;
 
-    use super::{Felt, NoteInputs, Serializable};
+    use super::{AccountId, Felt, NoteInputs, Serializable};
+    use crate::{
+        accounts::account_id::testing::ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+        NoteError,
+    };
 
     #[test]
     fn test_input_ordering() {
@@ -184,4 +220,22 @@ mod tests {
         let parsed_note_inputs = NoteInputs::read_from_bytes(&bytes).unwrap();
         assert_eq!(note_inputs, parsed_note_inputs);
     }
+
+    #[test]
+    fn test_typed_inputs() {
+        // inputs laid out as expected by the P2IDR note script: [target_account_id, reclaim_height]
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let inputs = NoteInputs::new(vec![target.into(), Felt::from(42_u32)]).unwrap();
+
+        assert_eq!(inputs.as_account_id(0), Ok(target));
+        assert_eq!(inputs.as_u32(1), Ok(42));
+
+        assert!(matches!(inputs.as_account_id(1), Err(NoteError::InvalidNoteInputAccountId(_))));
+        assert!(matches!(inputs.as_u32(0), Err(NoteError::InvalidNoteInputU32(_))));
+        assert_eq!(
+            inputs.as_u32(2),
+            Err(NoteError::NoteInputIndexOutOfBounds { offset: 2, num_inputs: 2 })
+        );
+    }
 }
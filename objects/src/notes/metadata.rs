@@ -86,6 +86,39 @@ impl NoteMetadata {
     }
 }
 
+// NOTE METADATA VIEW
+// ================================================================================================
+
+/// A summary of the fields of [NoteMetadata] most commonly used when inspecting notes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NoteMetadataView {
+    /// The ID of the account which created the note.
+    pub sender: AccountId,
+
+    /// Defines how the note is to be stored (e.g., on-chain or off-chain).
+    pub note_type: NoteType,
+
+    /// A value which can be used by the recipient(s) to identify notes intended for them.
+    pub tag: NoteTag,
+
+    /// An arbitrary user-defined value.
+    pub aux: Felt,
+}
+
+impl From<&NoteMetadata> for NoteMetadataView {
+    fn from(metadata: &NoteMetadata) -> Self {
+        Self {
+            sender: metadata.sender,
+            note_type: metadata.note_type,
+            tag: metadata.tag,
+            aux: metadata.aux,
+        }
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
 impl From<NoteMetadata> for Word {
     fn from(metadata: NoteMetadata) -> Self {
         (&metadata).into()
@@ -152,7 +185,7 @@ impl Deserializable for NoteMetadata {
 /// - Bits 37 to 6 (32 bits): NoteExecutionHint payload
 /// - Bits 5 to 0 (6 bits): NoteExecutionHint tag
 fn merge_type_and_hint(note_type: NoteType, note_execution_hint: NoteExecutionHint) -> u64 {
-    let type_nibble = note_type as u64 & 0b11;
+    let type_nibble = note_type.as_u8() as u64 & 0b11;
     let (tag_nibble, payload_u32) = note_execution_hint.into_parts();
 
     let payload_section = payload_u32 as u64;
@@ -1,3 +1,4 @@
+use alloc::collections::BTreeSet;
 use core::ops::Deref;
 
 use miden_crypto::{
@@ -6,7 +7,12 @@ use miden_crypto::{
 };
 use vm_processor::DeserializationError;
 
-use crate::{accounts::AccountId, assets::Asset, Digest, Felt, Hasher, NoteError, WORD_SIZE, ZERO};
+use crate::{
+    accounts::AccountId,
+    assets::Asset,
+    transaction::{InputNote, InputNotes},
+    Digest, Felt, Hasher, NoteError, WORD_SIZE, ZERO,
+};
 
 mod assets;
 pub use assets::NoteAssets;
@@ -21,11 +27,14 @@ mod inputs;
 pub use inputs::NoteInputs;
 
 mod metadata;
-pub use metadata::NoteMetadata;
+pub use metadata::{NoteMetadata, NoteMetadataView};
 
 mod execution_hint;
 pub use execution_hint::NoteExecutionHint;
 
+mod note_aux;
+pub use note_aux::NoteAux;
+
 mod note_id;
 pub use note_id::NoteId;
 
@@ -36,7 +45,7 @@ mod note_type;
 pub use note_type::NoteType;
 
 mod nullifier;
-pub use nullifier::Nullifier;
+pub use nullifier::{nullifiers_for, Nullifier};
 
 mod location;
 pub use location::{NoteInclusionProof, NoteLocation};
@@ -45,7 +54,7 @@ mod partial;
 pub use partial::PartialNote;
 
 mod recipient;
-pub use recipient::NoteRecipient;
+pub use recipient::{compute_recipient, NoteRecipient};
 
 mod script;
 pub use script::NoteScript;
@@ -118,6 +127,11 @@ impl Note {
         self.header.metadata()
     }
 
+    /// Returns a summary of the note's sender, type, tag and aux value.
+    pub fn metadata_summary(&self) -> NoteMetadataView {
+        self.metadata().into()
+    }
+
     /// Returns the note's assets.
     pub fn assets(&self) -> &NoteAssets {
         self.details.assets()
@@ -133,6 +147,13 @@ impl Note {
         self.details.script()
     }
 
+    /// Returns the MAST root of the note's script.
+    ///
+    /// This can be compared against the roots of well-known scripts to classify the note.
+    pub fn script_root(&self) -> Digest {
+        self.script().hash()
+    }
+
     /// Returns the note's recipient inputs which customizes the script's behavior.
     pub fn inputs(&self) -> &NoteInputs {
         self.details.inputs()
@@ -159,6 +180,39 @@ impl Note {
     pub fn hash(&self) -> Digest {
         self.header.hash()
     }
+
+    /// Returns true if this note is present in the provided input notes together with an
+    /// inclusion proof.
+    ///
+    /// A note which is present as an unauthenticated input note (i.e., it still requires a proof)
+    /// or which is not present at all is not considered to be authenticated.
+    pub fn is_authenticated_in(&self, inputs: &InputNotes<InputNote>) -> bool {
+        let note_id = self.id();
+        inputs.iter().any(|input| input.id() == note_id && input.proof().is_some())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that no two of the provided notes share the same serial number.
+///
+/// Notes with the same serial number, script and inputs have colliding nullifiers, and thus
+/// this check should be performed when creating a batch of notes.
+///
+/// # Errors
+/// Returns an error containing the duplicated serial number if two notes share the same serial
+/// number.
+pub fn assert_unique_serials(notes: &[Note]) -> Result<(), NoteError> {
+    let mut serials = BTreeSet::new();
+    for note in notes {
+        let serial_num = note.serial_num();
+        if !serials.insert(Digest::from(serial_num)) {
+            return Err(NoteError::DuplicateSerialNumber(serial_num));
+        }
+    }
+
+    Ok(())
 }
 
 // DEREFERENCING
@@ -244,3 +298,70 @@ impl Deserializable for Note {
         Ok(Self::new(assets, metadata, recipient))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        assert_unique_serials, Note, NoteAssets, NoteExecutionHint, NoteInclusionProof, NoteInputs,
+        NoteMetadata, NoteRecipient, NoteScript, NoteTag, NoteType,
+    };
+    use crate::{
+        accounts::{account_id::testing::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, AccountId},
+        assets::{Asset, FungibleAsset},
+        crypto::merkle::MerklePath,
+        transaction::{InputNote, InputNotes},
+        Felt, NoteError, ZERO,
+    };
+
+    fn build_note(serial_num: u64) -> Note {
+        let faucet = AccountId::new_unchecked(Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN));
+        let metadata = NoteMetadata::new(
+            faucet,
+            NoteType::Public,
+            NoteTag::from(0),
+            NoteExecutionHint::None,
+            ZERO,
+        )
+        .unwrap();
+        let serial_num = [Felt::new(serial_num), ZERO, ZERO, ZERO];
+        let recipient =
+            NoteRecipient::new(serial_num, NoteScript::mock(), NoteInputs::new(vec![]).unwrap());
+        let asset = Asset::Fungible(FungibleAsset::new(faucet, 100).unwrap());
+
+        Note::new(NoteAssets::new(vec![asset]).unwrap(), metadata, recipient)
+    }
+
+    #[test]
+    fn test_note_is_authenticated_in() {
+        let authenticated = build_note(1);
+        let unauthenticated = build_note(2);
+        let missing = build_note(3);
+
+        let proof = NoteInclusionProof::new(1, 0, MerklePath::new(vec![])).unwrap();
+        let inputs = InputNotes::new(vec![
+            InputNote::authenticated(authenticated.clone(), proof),
+            InputNote::unauthenticated(unauthenticated.clone()),
+        ])
+        .unwrap();
+
+        assert!(authenticated.is_authenticated_in(&inputs));
+        assert!(!unauthenticated.is_authenticated_in(&inputs));
+        assert!(!missing.is_authenticated_in(&inputs));
+    }
+
+    #[test]
+    fn test_assert_unique_serials() {
+        let notes = [build_note(1), build_note(2), build_note(3)];
+        assert!(assert_unique_serials(&notes).is_ok());
+        assert!(assert_unique_serials(&[]).is_ok());
+
+        let duplicate = [build_note(1), build_note(2), build_note(1)];
+        assert_eq!(
+            assert_unique_serials(&duplicate),
+            Err(NoteError::DuplicateSerialNumber(duplicate[0].serial_num()))
+        );
+    }
+}
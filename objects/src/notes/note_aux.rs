@@ -0,0 +1,115 @@
+// NOTE AUX
+// ================================================================================================
+
+use vm_core::Felt;
+
+// CONSTANTS
+// ================================================================================================
+
+const TIMESTAMP_TAG: u32 = 1;
+const FLAGS_TAG: u32 = 2;
+
+/// A typed wrapper for the `aux` field of [NoteMetadata](super::NoteMetadata).
+///
+/// The `aux` field is an opaque [Felt] from the perspective of the protocol. This wrapper
+/// standardizes two common uses of it: encoding a timestamp, and encoding a set of application
+/// flags.
+///
+/// The encoding follows a tag-payload layout: the upper 32 bits of the field specify how the value
+/// should be interpreted and the lower 32 bits contain the payload. Values with any other tag
+/// (including the default zero tag) are treated as raw values which cannot be decoded.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct NoteAux(Felt);
+
+impl NoteAux {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a [NoteAux] encoding the specified timestamp.
+    pub fn from_timestamp(timestamp: u32) -> Self {
+        Self::from_parts(TIMESTAMP_TAG, timestamp)
+    }
+
+    /// Returns a [NoteAux] encoding the specified flags.
+    pub fn from_flags(flags: u32) -> Self {
+        Self::from_parts(FLAGS_TAG, flags)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the timestamp encoded in this [NoteAux], or `None` if it does not encode a
+    /// timestamp.
+    pub fn timestamp(&self) -> Option<u32> {
+        self.payload_for(TIMESTAMP_TAG)
+    }
+
+    /// Returns the flags encoded in this [NoteAux], or `None` if it does not encode flags.
+    pub fn flags(&self) -> Option<u32> {
+        self.payload_for(FLAGS_TAG)
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    fn from_parts(tag: u32, payload: u32) -> Self {
+        // the tag is small enough for the resulting value to always be smaller than the modulus
+        Self(Felt::new(((tag as u64) << 32) | payload as u64))
+    }
+
+    fn payload_for(&self, tag: u32) -> Option<u32> {
+        let value = self.0.as_int();
+        if (value >> 32) as u32 == tag {
+            Some(value as u32)
+        } else {
+            None
+        }
+    }
+}
+
+// CONVERSIONS
+// ================================================================================================
+
+impl From<Felt> for NoteAux {
+    fn from(value: Felt) -> Self {
+        Self(value)
+    }
+}
+
+impl From<NoteAux> for Felt {
+    fn from(value: NoteAux) -> Self {
+        value.0
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_round_trip() {
+        let timestamp = 1_726_000_000;
+        let aux = NoteAux::from_timestamp(timestamp);
+        assert_eq!(aux.timestamp(), Some(timestamp));
+        assert_eq!(aux.flags(), None);
+
+        let felt: Felt = aux.into();
+        let decoded = NoteAux::from(felt);
+        assert_eq!(decoded, aux);
+        assert_eq!(decoded.timestamp(), Some(timestamp));
+    }
+
+    #[test]
+    fn test_flags_and_raw_values() {
+        let aux = NoteAux::from_flags(0b1010);
+        assert_eq!(aux.flags(), Some(0b1010));
+        assert_eq!(aux.timestamp(), None);
+
+        let raw = NoteAux::from(Felt::new(42));
+        assert_eq!(raw.timestamp(), None);
+        assert_eq!(raw.flags(), None);
+    }
+}
@@ -29,6 +29,10 @@ pub struct NoteId(Digest);
 
 impl NoteId {
     /// Returns a new [NoteId] instantiated from the provided note components.
+    ///
+    /// The ID is computed as hash(recipient, asset_commitment), which matches the derivation used
+    /// by the transaction kernel. This allows computing the ID of a note without having the full
+    /// note.
     pub fn new(recipient: Digest, asset_commitment: Digest) -> Self {
         Self(Hasher::merge(&[recipient, asset_commitment]))
     }
@@ -142,6 +146,18 @@ mod tests {
     use alloc::string::ToString;
 
     use super::NoteId;
+    use crate::{
+        accounts::{
+            account_id::testing::{ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER},
+            AccountId,
+        },
+        assets::FungibleAsset,
+        notes::{
+            Note, NoteAssets, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient,
+            NoteScript, NoteTag, NoteType,
+        },
+        Felt, ZERO,
+    };
 
     #[test]
     fn note_id_try_from_hex() {
@@ -150,4 +166,28 @@ mod tests {
 
         assert_eq!(note_id.inner().to_string(), note_id_hex)
     }
+
+    #[test]
+    fn note_id_from_parts() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let faucet = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let assets =
+            NoteAssets::new(vec![FungibleAsset::new(faucet, 100).unwrap().into()]).unwrap();
+        let metadata = NoteMetadata::new(
+            sender,
+            NoteType::Private,
+            NoteTag::from(0),
+            NoteExecutionHint::None,
+            ZERO,
+        )
+        .unwrap();
+        let serial_num = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let inputs = NoteInputs::new(vec![Felt::new(5)]).unwrap();
+        let recipient = NoteRecipient::new(serial_num, NoteScript::mock(), inputs);
+        let note = Note::new(assets, metadata, recipient);
+
+        let note_id = NoteId::new(note.recipient().digest(), note.assets().commitment());
+        assert_eq!(note_id, note.id());
+    }
 }
@@ -111,6 +111,37 @@ impl NoteTag {
         }
     }
 
+    /// Returns a new [NoteTag] for a note targeting the specified public account.
+    ///
+    /// This is equivalent to [NoteTag::from_account_id()] with [NoteExecutionMode::Network]: the
+    /// most significant bit of the tag is set to `0b0` and the remaining bits are set to the 31
+    /// most significant bits of the account ID. The target can be recovered from the tag via
+    /// [NoteTag::target_account_id_prefix()].
+    ///
+    /// # Errors
+    ///
+    /// This will return an error if the account_id is not for a public account.
+    pub fn for_public_account(account_id: AccountId) -> Result<Self, NoteError> {
+        Self::from_account_id(account_id, NoteExecutionMode::Network)
+    }
+
+    /// Returns a new [NoteTag] for local execution with the specified application-defined
+    /// `payload`.
+    ///
+    /// The two high bits are set to `0b11`, which allows for any note type to be used, and the
+    /// remaining 30 bits are set to `payload`.
+    ///
+    /// # Errors
+    ///
+    /// - If `payload` is larger than or equal to $2^{30}$.
+    pub fn for_local_use(payload: u32) -> Result<Self, NoteError> {
+        if (payload >> 30) != 0 {
+            return Err(NoteError::InvalidNoteTagPayload(payload));
+        }
+
+        Ok(Self(LOCAL_EXECUTION_WITH_ALL_NOTE_TYPES_ALLOWED | payload))
+    }
+
     /// Returns a new [NoteTag] instantiated for a custom use case which requires a public note.
     ///
     /// The public use_case tag requires a [NoteType::Public] note.
@@ -189,6 +220,19 @@ impl NoteTag {
         }
     }
 
+    /// Returns the 31 most significant bits of the ID of the account targeted by this tag, placed
+    /// in the high bits of the returned value, or None if this tag does not have a specific target.
+    ///
+    /// For a tag built via [NoteTag::for_public_account()], the returned value is equal to the
+    /// target account ID with its 33 least significant bits set to zero.
+    pub fn target_account_id_prefix(&self) -> Option<u64> {
+        if self.is_single_target() {
+            Some((self.0 as u64) << 33)
+        } else {
+            None
+        }
+    }
+
     /// Returns the inner u32 value of this tag.
     pub fn inner(&self) -> u32 {
         self.0
@@ -554,4 +598,38 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_for_public_account() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let tag = NoteTag::for_public_account(account_id).unwrap();
+
+        assert!(tag.is_single_target());
+        assert_eq!(tag.execution_hint(), NoteExecutionMode::Network);
+        assert_eq!(tag, NoteTag::from_account_id(account_id, NoteExecutionMode::Network).unwrap());
+
+        // the target can be recovered from the tag and round-trips through the raw value
+        let prefix = u64::from(account_id) & !((1 << 33) - 1);
+        assert_eq!(tag.target_account_id_prefix(), Some(prefix));
+        assert_eq!(NoteTag::from(tag.inner()).target_account_id_prefix(), Some(prefix));
+
+        // private accounts cannot be targeted by network tags
+        let private_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        assert_eq!(
+            NoteTag::for_public_account(private_id),
+            Err(NoteError::NetworkExecutionRequiresOnChainAccount)
+        );
+    }
+
+    #[test]
+    fn test_for_local_use() {
+        let tag = NoteTag::for_local_use(0x1234_5678).unwrap();
+        assert_eq!(tag.inner(), 0xc000_0000 | 0x1234_5678);
+        assert_eq!(tag.execution_hint(), NoteExecutionMode::Local);
+        assert_eq!(tag.target_account_id_prefix(), None);
+        assert_eq!(tag.validate(NoteType::Private), Ok(tag));
+
+        assert_eq!(NoteTag::for_local_use(1 << 30), Err(NoteError::InvalidNoteTagPayload(1 << 30)));
+    }
 }
@@ -27,12 +27,32 @@ pub enum NoteType {
     Public = PUBLIC,
 }
 
+impl NoteType {
+    /// Returns the numeric value which encodes this note type.
+    pub const fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the note type encoded by the provided numeric value.
+    ///
+    /// # Errors
+    /// Returns an error if the value does not encode a valid note type.
+    pub fn try_from_u8(value: u8) -> Result<Self, NoteError> {
+        match value {
+            PRIVATE => Ok(NoteType::Private),
+            ENCRYPTED => Ok(NoteType::Encrypted),
+            PUBLIC => Ok(NoteType::Public),
+            _ => Err(NoteError::InvalidNoteTypeValue(value.into())),
+        }
+    }
+}
+
 // CONVERSIONS FROM NOTE TYPE
 // ================================================================================================
 
 impl From<NoteType> for Felt {
     fn from(id: NoteType) -> Self {
-        Felt::new(id as u64)
+        Felt::from(id.as_u8())
     }
 }
 
@@ -43,12 +63,7 @@ impl TryFrom<u8> for NoteType {
     type Error = NoteError;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            PRIVATE => Ok(NoteType::Private),
-            ENCRYPTED => Ok(NoteType::Encrypted),
-            PUBLIC => Ok(NoteType::Public),
-            _ => Err(NoteError::InvalidNoteTypeValue(value.into())),
-        }
+        Self::try_from_u8(value)
     }
 }
 
@@ -90,26 +105,40 @@ impl TryFrom<Felt> for NoteType {
 
 impl Serializable for NoteType {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        (*self as u8).write_into(target)
+        self.as_u8().write_into(target)
     }
 }
 
 impl Deserializable for NoteType {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let discriminat = u8::read_from(source)?;
-
-        let note_type = match discriminat {
-            PRIVATE => NoteType::Private,
-            ENCRYPTED => NoteType::Encrypted,
-            PUBLIC => NoteType::Public,
-            v => {
-                return Err(DeserializationError::InvalidValue(format!(
-                    "Value {} is not a valid NoteType",
-                    v
-                )))
-            },
-        };
-
-        Ok(note_type)
+        let discriminant = u8::read_from(source)?;
+
+        NoteType::try_from_u8(discriminant).map_err(|_| {
+            DeserializationError::InvalidValue(format!(
+                "Value {} is not a valid NoteType",
+                discriminant
+            ))
+        })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Deserializable, NoteType, Serializable};
+    use crate::NoteError;
+
+    #[test]
+    fn test_note_type_u8_round_trip() {
+        for note_type in [NoteType::Private, NoteType::Encrypted, NoteType::Public] {
+            assert_eq!(NoteType::try_from_u8(note_type.as_u8()), Ok(note_type));
+            assert_eq!(NoteType::read_from_bytes(&note_type.to_bytes()).unwrap(), note_type);
+        }
+
+        assert_eq!(NoteType::try_from_u8(0), Err(NoteError::InvalidNoteTypeValue(0)));
+        assert_eq!(NoteType::try_from_u8(4), Err(NoteError::InvalidNoteTypeValue(4)));
+        assert!(NoteType::read_from_bytes(&[4]).is_err());
     }
 }
@@ -1,11 +1,11 @@
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use core::fmt::{Debug, Display, Formatter};
 
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher, Note,
     NoteDetails, Serializable, Word, WORD_SIZE, ZERO,
 };
-use crate::utils::{hex_to_bytes, HexParseError};
+use crate::utils::{hex_to_bytes, par_map_chunks, HexParseError};
 
 // NULLIFIER
 // ================================================================================================
@@ -82,6 +82,17 @@ impl Debug for Nullifier {
     }
 }
 
+// BATCH DERIVATION
+// ================================================================================================
+
+/// Computes the nullifiers of the provided notes.
+///
+/// The nullifiers are derived from the notes' details and are returned in the same order as the
+/// input notes. With the `concurrent` feature enabled, the work is split across multiple threads.
+pub fn nullifiers_for(notes: &[Note]) -> Vec<Nullifier> {
+    par_map_chunks(notes, |note| Nullifier::from(&note.details))
+}
+
 // CONVERSIONS INTO NULLIFIER
 // ================================================================================================
 
@@ -156,7 +167,19 @@ impl Deserializable for Nullifier {
 
 #[cfg(test)]
 mod tests {
-    use crate::notes::Nullifier;
+    use alloc::vec::Vec;
+
+    use vm_core::Felt;
+
+    use super::nullifiers_for;
+    use crate::{
+        accounts::{account_id::testing::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, AccountId},
+        assets::{Asset, FungibleAsset},
+        notes::{
+            Note, NoteAssets, NoteExecutionHint, NoteInputs, NoteMetadata, NoteRecipient,
+            NoteScript, NoteTag, NoteType, Nullifier,
+        },
+    };
 
     #[test]
     fn test_from_hex_and_back() {
@@ -165,4 +188,31 @@ mod tests {
 
         assert_eq!(nullifier_hex, nullifier.to_hex());
     }
+
+    #[test]
+    fn test_nullifiers_for() {
+        let faucet = AccountId::new_unchecked(Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN));
+        let metadata = NoteMetadata::new(
+            faucet,
+            NoteType::Public,
+            NoteTag::from(0),
+            NoteExecutionHint::None,
+            Felt::new(0),
+        )
+        .unwrap();
+
+        let notes: Vec<Note> = (0..17u64)
+            .map(|i| {
+                let serial_num = [Felt::new(i), Felt::new(1), Felt::new(2), Felt::new(3)];
+                let inputs = NoteInputs::new(vec![Felt::new(i)]).unwrap();
+                let recipient = NoteRecipient::new(serial_num, NoteScript::mock(), inputs);
+                let asset = Asset::Fungible(FungibleAsset::new(faucet, 100 + i).unwrap());
+                Note::new(NoteAssets::new(vec![asset]).unwrap(), metadata, recipient)
+            })
+            .collect();
+
+        let expected: Vec<Nullifier> = notes.iter().map(Note::nullifier).collect();
+        assert_eq!(nullifiers_for(&notes), expected);
+        assert!(nullifiers_for(&[]).is_empty());
+    }
 }
@@ -28,7 +28,7 @@ pub struct NoteRecipient {
 
 impl NoteRecipient {
     pub fn new(serial_num: Word, script: NoteScript, inputs: NoteInputs) -> Self {
-        let digest = compute_recipient_digest(serial_num, &script, &inputs);
+        let digest = compute_recipient(serial_num, script.hash(), &inputs);
         Self { serial_num, script, inputs, digest }
     }
 
@@ -67,9 +67,14 @@ impl NoteRecipient {
     }
 }
 
-fn compute_recipient_digest(serial_num: Word, script: &NoteScript, inputs: &NoteInputs) -> Digest {
+/// Computes the recipient digest from the provided serial number, note script root and inputs.
+///
+/// The digest is computed in the same way as in the transaction kernel:
+///
+/// > hash(hash(hash(serial_num, [0; 4]), script_root), input_hash)
+pub fn compute_recipient(serial_num: Word, script_root: Digest, inputs: &NoteInputs) -> Digest {
     let serial_num_hash = Hasher::merge(&[serial_num.into(), Digest::default()]);
-    let merge_script = Hasher::merge(&[serial_num_hash, script.hash()]);
+    let merge_script = Hasher::merge(&[serial_num_hash, script_root]);
     Hasher::merge(&[merge_script, inputs.commitment()])
 }
 
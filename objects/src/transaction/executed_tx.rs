@@ -6,7 +6,7 @@ use super::{
     InputNotes, NoteId, OutputNotes, TransactionArgs, TransactionId, TransactionInputs,
     TransactionOutputs, TransactionWitness,
 };
-use crate::accounts::AccountCode;
+use crate::{accounts::AccountCode, notes::Nullifier, Digest};
 
 // EXECUTED TRANSACTION
 // ================================================================================================
@@ -98,6 +98,15 @@ impl ExecutedTransaction {
         &self.tx_outputs.output_notes
     }
 
+    /// Returns the IDs of the notes created in this transaction.
+    ///
+    /// The IDs are returned in the order in which the notes were created during transaction
+    /// execution. This order is deterministic for a given set of transaction inputs, and it is the
+    /// order in which the notes are committed to by the output notes commitment.
+    pub fn output_note_ids(&self) -> Vec<NoteId> {
+        self.output_notes().iter().map(|note| note.id()).collect()
+    }
+
     /// Returns a reference to the transaction args.
     pub fn tx_args(&self) -> &TransactionArgs {
         &self.tx_args
@@ -130,6 +139,20 @@ impl ExecutedTransaction {
         &self.tx_measurements
     }
 
+    /// Returns the ID of the account against which this transaction was executed together with the
+    /// hash of the account after the transaction was executed.
+    ///
+    /// This is the update to be applied to the account tree when the transaction is included in a
+    /// block.
+    pub fn to_account_update(&self) -> (AccountId, Digest) {
+        (self.account_id(), self.final_account().hash())
+    }
+
+    /// Returns the nullifiers of the notes consumed in this transaction.
+    pub fn consumed_nullifiers(&self) -> Vec<Nullifier> {
+        self.tx_inputs.input_nullifiers()
+    }
+
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -1,7 +1,7 @@
 use alloc::{collections::BTreeSet, vec::Vec};
 use core::fmt::Debug;
 
-use super::{BlockHeader, ChainMmr, Digest, Felt, Hasher, Word};
+use super::{BlockHeader, ChainMmr, Digest, Felt, Hasher, InputNoteCommitment, Word};
 use crate::{
     accounts::{Account, AccountId},
     notes::{Note, NoteId, NoteInclusionProof, NoteLocation, Nullifier},
@@ -112,6 +112,13 @@ impl TransactionInputs {
         &self.input_notes
     }
 
+    /// Returns the nullifiers of the notes to be consumed in the transaction.
+    ///
+    /// This can be used to mark the input notes as pending before the transaction is executed.
+    pub fn input_nullifiers(&self) -> Vec<Nullifier> {
+        self.input_notes.iter().map(|note| note.nullifier()).collect()
+    }
+
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -313,6 +320,17 @@ impl<T: Deserializable + ToInputNoteCommitments> Deserializable for InputNotes<T
 // HELPER FUNCTIONS
 // ------------------------------------------------------------------------------------------------
 
+/// Returns the commitment to the input notes of a transaction which consumes only authenticated
+/// notes with the provided nullifiers.
+///
+/// The commitment is computed in the same way as by the transaction kernel, and thus can be used
+/// to verify the input notes commitment claimed by a transaction.
+pub fn compute_input_notes_commitment(nullifiers: &[Nullifier]) -> Digest {
+    let notes: Vec<InputNoteCommitment> =
+        nullifiers.iter().copied().map(InputNoteCommitment::from).collect();
+    build_input_note_commitment(&notes)
+}
+
 fn build_input_note_commitment<T: ToInputNoteCommitments>(notes: &[T]) -> Digest {
     // Note: This implementation must be kept in sync with the kernel's `process_input_notes_data`
     if notes.is_empty() {
@@ -491,3 +509,46 @@ pub fn validate_account_seed(
         (false, None) => Ok(()),
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{compute_input_notes_commitment, InputNotes};
+    use crate::{
+        notes::Nullifier, transaction::InputNoteCommitment, Digest, Felt, Hasher, EMPTY_WORD,
+    };
+
+    #[test]
+    fn test_compute_input_notes_commitment() {
+        let nullifiers: Vec<Nullifier> = (0..3u64)
+            .map(|i| {
+                Nullifier::from([
+                    Felt::new(i),
+                    Felt::new(i + 1),
+                    Felt::new(i + 2),
+                    Felt::new(i + 3),
+                ])
+            })
+            .collect();
+
+        // each authenticated note is represented by its nullifier followed by an empty word
+        let mut elements = Vec::new();
+        for nullifier in nullifiers.iter() {
+            elements.extend_from_slice(nullifier.as_elements());
+            elements.extend_from_slice(&EMPTY_WORD);
+        }
+        let expected = Hasher::hash_elements(&elements);
+        assert_eq!(compute_input_notes_commitment(&nullifiers), expected);
+
+        let input_notes =
+            InputNotes::new(nullifiers.iter().copied().map(InputNoteCommitment::from).collect())
+                .unwrap();
+        assert_eq!(input_notes.commitment(), expected);
+
+        assert_eq!(compute_input_notes_commitment(&[]), Digest::default());
+    }
+}
@@ -16,7 +16,10 @@ mod tx_witness;
 
 pub use chain_mmr::ChainMmr;
 pub use executed_tx::{ExecutedTransaction, TransactionMeasurements};
-pub use inputs::{InputNote, InputNotes, ToInputNoteCommitments, TransactionInputs};
+pub use inputs::{
+    compute_input_notes_commitment, InputNote, InputNotes, ToInputNoteCommitments,
+    TransactionInputs,
+};
 pub use outputs::{OutputNote, OutputNotes, TransactionOutputs};
 pub use proven_tx::{
     InputNoteCommitment, ProvenTransaction, ProvenTransactionBuilder, TxAccountUpdate,
@@ -2,7 +2,7 @@ use alloc::{collections::BTreeMap, string::ToString, sync::Arc, vec::Vec};
 use core::ops::Deref;
 
 use assembly::{Assembler, Compile};
-use miden_crypto::merkle::InnerNodeInfo;
+use miden_crypto::merkle::{InnerNodeInfo, MerkleError, MerklePath};
 use vm_core::{
     mast::{MastForest, MastNodeId},
     utils::{ByteReader, ByteWriter, Deserializable, Serializable},
@@ -12,8 +12,9 @@ use vm_processor::{AdviceInputs, AdviceMap, DeserializationError};
 
 use super::{Digest, Felt, Word};
 use crate::{
+    accounts::{Account, AccountHeader, StorageSlot},
     notes::{NoteDetails, NoteId},
-    TransactionScriptError,
+    TransactionScriptError, ZERO,
 };
 
 // TRANSACTION ARGS
@@ -141,6 +142,66 @@ impl TransactionArgs {
         }
     }
 
+    /// Populates the advice inputs with the data of the specified foreign account, making its
+    /// state available to the transaction kernel for foreign procedure invocation.
+    ///
+    /// The advice inputs' map is extended with the following keys:
+    ///
+    /// - [account_id, 0, 0, 0] |-> [[account_id, 0, 0, nonce], VAULT_ROOT, STORAGE_ROOT,
+    ///   CODE_ROOT].
+    /// - STORAGE_ROOT |-> [[STORAGE_SLOT_DATA]].
+    /// - CODE_ROOT |-> [[ACCOUNT_PROCEDURE_DATA]].
+    /// - leaf hash |-> leaf elements, for the leaves of each storage map of the account.
+    ///
+    /// The advice inputs' merkle store is extended with the inner nodes of the account's storage
+    /// maps, and with the nodes of `account_path`, which must be the path to the account's leaf in
+    /// the account tree of the reference block.
+    ///
+    /// The code of the foreign account must additionally be loaded into the executor, so that its
+    /// procedures can be executed.
+    ///
+    /// # Errors
+    /// Returns an error if the account's leaf index is not valid for the depth of `account_path`.
+    pub fn add_foreign_account(
+        &mut self,
+        account: &Account,
+        account_path: &MerklePath,
+    ) -> Result<(), MerkleError> {
+        let (leaf_index, account_hash) = account.tree_entry();
+        let account_nodes = account_path.inner_nodes(leaf_index.value(), account_hash.into())?;
+
+        let header = AccountHeader::from(account);
+        let storage = account.storage();
+        let code = account.code();
+
+        self.advice_inputs.extend_map([
+            (
+                Digest::from([header.id().into(), ZERO, ZERO, ZERO]),
+                [
+                    &[header.id().into(), ZERO, ZERO, header.nonce()],
+                    header.vault_root().as_elements(),
+                    header.storage_commitment().as_elements(),
+                    header.code_commitment().as_elements(),
+                ]
+                .concat(),
+            ),
+            (header.storage_commitment(), storage.as_elements()),
+            (header.code_commitment(), code.as_elements()),
+        ]);
+
+        for slot in storage.slots() {
+            if let StorageSlot::Map(map) = slot {
+                self.advice_inputs.extend_merkle_store(map.inner_nodes());
+                self.advice_inputs
+                    .extend_map(map.leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+            }
+        }
+
+        self.advice_inputs.extend_merkle_store(account_nodes);
+
+        Ok(())
+    }
+
     /// Extends the internal advice inputs' map with the provided key-value pairs.
     pub fn extend_advice_map<T: IntoIterator<Item = (Digest, Vec<Felt>)>>(&mut self, iter: T) {
         self.advice_inputs.extend_map(iter)
@@ -273,10 +334,17 @@ impl Deserializable for TransactionScript {
 
 #[cfg(test)]
 mod tests {
+    use alloc::{collections::BTreeMap, vec::Vec};
+
+    use assembly::Assembler;
     use vm_core::utils::{Deserializable, Serializable};
     use vm_processor::AdviceMap;
 
-    use crate::transaction::TransactionArgs;
+    use crate::{
+        notes::NoteId,
+        transaction::{TransactionArgs, TransactionScript},
+        Digest, Felt, Word,
+    };
 
     #[test]
     fn test_tx_args_serialization() {
@@ -286,4 +354,34 @@ mod tests {
 
         assert_eq!(args, decoded);
     }
+
+    #[test]
+    fn test_tx_args_new_with_all_fields() {
+        let script_input_key: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let script_input: Vec<Felt> = vec![Felt::new(5)];
+        let tx_script = TransactionScript::compile(
+            "begin push.1 drop end",
+            [(script_input_key, script_input.clone())],
+            Assembler::default(),
+        )
+        .unwrap();
+
+        let note_id = NoteId::new(Digest::default(), Digest::default());
+        let note_arg: Word = [Felt::new(6), Felt::new(7), Felt::new(8), Felt::new(9)];
+        let note_args = BTreeMap::from([(note_id, note_arg)]);
+
+        let advice_key = Digest::new([Felt::new(10), Felt::new(11), Felt::new(12), Felt::new(13)]);
+        let advice_value: Vec<Felt> = vec![Felt::new(14), Felt::new(15)];
+        let mut advice_map = AdviceMap::default();
+        advice_map.insert(advice_key, advice_value.clone());
+
+        let args = TransactionArgs::new(Some(tx_script.clone()), Some(note_args), advice_map);
+
+        assert_eq!(args.tx_script(), Some(&tx_script));
+        assert_eq!(args.get_note_args(note_id), Some(&note_arg));
+        // the advice map contains both the provided entries and the transaction script inputs
+        let map = &args.advice_inputs().map;
+        assert_eq!(map.get(&advice_key), Some(advice_value.as_slice()));
+        assert_eq!(map.get(&script_input_key.into()), Some(script_input.as_slice()));
+    }
 }